@@ -0,0 +1,3 @@
+pub mod rf64;
+pub mod riff;
+pub mod writer;
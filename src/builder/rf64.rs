@@ -0,0 +1,92 @@
+//! Builder-side support for emitting `RF64`/`BW64` headers, the large-file RIFF variant used
+//! once a payload no longer fits the 32-bit size fields `RiffBuilder` otherwise relies on. See
+//! [`crate::eager::rf64`] for the matching read path.
+
+use crate::{
+    builder::riff::{ChunkBuilder, ChunkData, RiffBuilder},
+    error::RiffResult,
+    FourCC,
+};
+
+/// The sentinel `RiffBuilder`/`ChunkBuilder` size fields are filled with once their true size is
+/// only recoverable from the `ds64` chunk this module generates.
+const OVERSIZED: u32 = 0xFFFFFFFF;
+
+/// The 64-bit sizes that go into a generated `ds64` chunk: see [`crate::eager::rf64::Ds64Info`]
+/// for the read-side counterpart this mirrors.
+#[derive(Debug, Clone, Default)]
+pub struct Ds64Builder {
+    pub riff_size: u64,
+    pub data_size: u64,
+    pub sample_count: u64,
+    pub table: Vec<(FourCC, u64)>,
+}
+
+impl Ds64Builder {
+    pub fn new(riff_size: u64, data_size: u64) -> Self {
+        Ds64Builder {
+            riff_size,
+            data_size,
+            sample_count: 0,
+            table: Vec::new(),
+        }
+    }
+
+    /// Adds an override for a chunk other than the top-level `RIFF` or the `data` chunk, which
+    /// are already covered by `riff_size`/`data_size`.
+    pub fn with_override(mut self, id: FourCC, size: u64) -> Self {
+        self.table.push((id, size));
+        self
+    }
+
+    /// Lays out this `Ds64Builder`'s fields the same way [`crate::eager::rf64::Ds64Info::parse`]
+    /// reads them back: three 64-bit sizes, a `u32` table length, then that many
+    /// `(FourCC, u64)` entries.
+    fn to_raw_data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(28 + self.table.len() * 12);
+        data.extend_from_slice(&self.riff_size.to_le_bytes());
+        data.extend_from_slice(&self.data_size.to_le_bytes());
+        data.extend_from_slice(&self.sample_count.to_le_bytes());
+        data.extend_from_slice(&(self.table.len() as u32).to_le_bytes());
+        for (id, size) in &self.table {
+            data.extend_from_slice(id.as_bytes());
+            data.extend_from_slice(&size.to_le_bytes());
+        }
+        data
+    }
+
+    /// Builds the `ds64` chunk itself, ready to be written as the first child of the `RF64`
+    /// header.
+    pub fn to_chunk_builder(&self) -> ChunkBuilder {
+        ChunkBuilder::new_notype(FourCC::new(b"ds64"), ChunkData::RawData(self.to_raw_data()))
+    }
+}
+
+/// Writes `riff` as an `RF64` file: the `RF64` id, the [`OVERSIZED`] sentinel in place of the
+/// real size, the chunk type, a generated `ds64` chunk carrying `ds64.riff_size` and
+/// `ds64.data_size`, and then `riff`'s own chunks.
+///
+/// Callers are responsible for computing `ds64` themselves (in particular `riff_size`, which this
+/// function does not attempt to derive from `riff.payload_len` since that field is `u32` and may
+/// already have wrapped by the time a file needs `RF64` in the first place).
+pub fn write_rf64<W: crate::io::Write>(
+    riff: &RiffBuilder,
+    ds64: &Ds64Builder,
+    w: &mut W,
+) -> RiffResult<usize> {
+    let mut written = 0;
+    w.write_all(b"RF64")?;
+    written += 4;
+    w.write_all(&OVERSIZED.to_le_bytes())?;
+    written += 4;
+    w.write_all(riff.chunk_type.as_bytes())?;
+    written += 4;
+    written += ds64.to_chunk_builder().write_to(w)?;
+    for x in &riff.data {
+        // `write_to_rf64`, not `write_to`: a child whose true size overflows `u32` needs the
+        // `OVERSIZED` sentinel patched into its own header, not its (possibly already-wrapped)
+        // `payload_len` written verbatim.
+        written += x.write_to_rf64(w)?;
+    }
+    Ok(written)
+}
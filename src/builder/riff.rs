@@ -1,7 +1,10 @@
+use std::convert::TryFrom;
+
 use crate::constants::RIFF_ID;
 
 use crate::{
-    error::{RiffError, RiffResult},
+    eager::riff::{ChunkRam, ChunkRamContent, RiffRam},
+    error::{RiffError, RiffErrorKind, RiffResult},
     FourCC,
 };
 
@@ -67,7 +70,7 @@ impl ChunkBuilder {
             self.payload_len += 4;
         }
         match self.data {
-            ChunkData::RawData(_) => return Err(RiffError::MismatchChunkAdded),
+            ChunkData::RawData(_) => return Err(RiffErrorKind::MismatchChunkAdded.into()),
             ChunkData::ChunkList(ref mut vec) => {
                 self.payload_len += vec.iter().map(|x| x.payload_len + 8).sum::<u32>();
                 vec.push(chunk);
@@ -129,6 +132,113 @@ impl ChunkBuilder {
         }
         result
     }
+
+    /// This chunk's true total size (any chunk type plus its payload), computed by walking
+    /// `data` directly rather than trusting `payload_len` — which is a `u32` and may have already
+    /// wrapped if a descendant's raw data exceeds `u32::MAX` bytes.
+    ///
+    /// Used by [`write_to_rf64`](Self::write_to_rf64) to decide whether a chunk's header needs
+    /// the RF64 `OVERSIZED` sentinel in place of its real length.
+    pub(crate) fn true_payload_len(&self) -> u64 {
+        let type_len: u64 = if self.chunk_type.is_some() { 4 } else { 0 };
+        let data_len: u64 = match &self.data {
+            // `fit_data` already appended a pad byte to odd-length raw data; `payload_len`'s
+            // parity (which a `u32` wrap doesn't change) says whether it did.
+            ChunkData::RawData(raw) => raw.len() as u64 - (self.payload_len % 2) as u64,
+            // A child's own `true_payload_len` already counts its chunk type; its pad byte is
+            // part of this parent's payload.
+            ChunkData::ChunkList(children) => children
+                .iter()
+                .map(|child| {
+                    let len = child.true_payload_len();
+                    8 + len + len % 2
+                })
+                .sum(),
+        };
+        type_len + data_len
+    }
+
+    /// Like [`write_to`](Self::write_to), but writes this chunk's (and every descendant's)
+    /// length field from [`true_payload_len`](Self::true_payload_len) rather than `payload_len`,
+    /// writing the RF64 `OVERSIZED` sentinel (`0xFFFF_FFFF`) in place of any length that
+    /// overflows `u32`. Used by [`crate::builder::rf64::write_rf64`]; the caller is still
+    /// responsible for recording the true sizes this produces in the `ds64` chunk written
+    /// alongside this tree.
+    pub(crate) fn write_to_rf64<W: crate::io::Write>(&self, w: &mut W) -> RiffResult<usize> {
+        const OVERSIZED: u32 = 0xFFFF_FFFF;
+
+        let mut written = 0;
+        w.write_all(self.chunk_id.as_bytes())?;
+        written += 4;
+        let true_len = self.true_payload_len();
+        match u32::try_from(true_len) {
+            Ok(len) => w.write_all(&len.to_le_bytes())?,
+            Err(_) => w.write_all(&OVERSIZED.to_le_bytes())?,
+        }
+        written += 4;
+        if let Some(chunk_type) = &self.chunk_type {
+            w.write_all(chunk_type.as_bytes())?;
+            written += 4;
+        }
+        match &self.data {
+            ChunkData::RawData(raw) => {
+                w.write_all(raw)?;
+                written += raw.len();
+            }
+            ChunkData::ChunkList(children) => {
+                for child in children {
+                    written += child.write_to_rf64(w)?;
+                }
+            }
+        }
+        Ok(written)
+    }
+
+    /// Streams this `ChunkBuilder` into `w`, one piece at a time, instead of appending to a
+    /// shared in-memory buffer. Never holds more than this chunk's own header or raw payload in
+    /// memory at once; nested chunks recurse and write themselves directly.
+    pub(crate) fn write_to<W: crate::io::Write>(&self, w: &mut W) -> RiffResult<usize> {
+        let mut written = 0;
+        w.write_all(self.chunk_id.as_bytes())?;
+        written += 4;
+        w.write_all(&self.payload_len.to_le_bytes())?;
+        written += 4;
+        if let Some(chunk_type) = &self.chunk_type {
+            w.write_all(chunk_type.as_bytes())?;
+            written += 4;
+        }
+        match &self.data {
+            ChunkData::RawData(raw) => {
+                w.write_all(raw)?;
+                written += raw.len();
+            }
+            ChunkData::ChunkList(chunks) => {
+                for x in chunks {
+                    written += x.write_to(w)?;
+                }
+            }
+        }
+        Ok(written)
+    }
+
+    /// Writes this `ChunkBuilder` directly into a `bytes::BufMut`, so callers can serialize into
+    /// a preallocated `BytesMut` (or a chained buffer) without an intermediate `Vec<u8>`.
+    #[cfg(feature = "bytes")]
+    fn encode<B: bytes::BufMut>(&self, buf: &mut B) {
+        buf.put_slice(self.chunk_id.as_bytes());
+        buf.put_slice(&self.payload_len.to_le_bytes());
+        if let Some(chunk_type) = &self.chunk_type {
+            buf.put_slice(chunk_type.as_bytes());
+        }
+        match &self.data {
+            ChunkData::RawData(raw) => buf.put_slice(raw),
+            ChunkData::ChunkList(chunks) => {
+                for x in chunks {
+                    x.encode(buf);
+                }
+            }
+        }
+    }
 }
 
 /// This is technically just a helper function that will create a Chunk with proper RIFF formatting.
@@ -191,6 +301,37 @@ impl RiffBuilder {
         result
     }
 
+    /// Streams this already-built tree into `w`, writing each chunk's header and payload
+    /// directly instead of materializing the whole file into one `Vec<u8>` first (as
+    /// [`to_bytes`](RiffBuilder::to_bytes) does) — useful for multi-gigabyte payloads. Returns the
+    /// total number of bytes written.
+    pub fn write_to<W: crate::io::Write>(&self, w: &mut W) -> RiffResult<usize> {
+        let mut written = 0;
+        w.write_all(RIFF_ID)?;
+        written += 4;
+        w.write_all(&self.payload_len.to_le_bytes())?;
+        written += 4;
+        w.write_all(&self.chunk_type.data)?;
+        written += 4;
+        for x in &self.data {
+            written += x.write_to(w)?;
+        }
+        Ok(written)
+    }
+
+    /// Serializes this `RiffBuilder` directly into a `bytes::BufMut`, e.g. a preallocated
+    /// `BytesMut` or a `chain`ed buffer, avoiding the intermediate `Vec<u8>` that [`to_bytes`](RiffBuilder::to_bytes)
+    /// allocates.
+    #[cfg(feature = "bytes")]
+    pub fn encode<B: bytes::BufMut>(&self, buf: &mut B) {
+        buf.put_slice(RIFF_ID);
+        buf.put_slice(&self.payload_len.to_le_bytes());
+        buf.put_slice(&self.chunk_type.data);
+        for x in &self.data {
+            x.encode(buf);
+        }
+    }
+
     /// Appends a `ChunkBuilder` to this `RiffBuilder`.
     pub fn add_chunk(mut self, chunk: ChunkBuilder) -> Self {
         self.payload_len += 8;
@@ -216,3 +357,52 @@ pub enum ChunkData {
     RawData(Vec<u8>),
     ChunkList(Vec<ChunkBuilder>),
 }
+
+/// Closes the read-modify-write loop: a `ChunkRamContent` parsed out of an existing file can be
+/// turned back into a `ChunkBuilder`, edited (chunks inserted or dropped from the resulting
+/// `ChunkList`), and re-serialized, with `payload_len` recomputed from scratch rather than copied
+/// from the source file.
+impl From<ChunkRamContent<'_>> for ChunkBuilder {
+    fn from(content: ChunkRamContent<'_>) -> Self {
+        match content {
+            ChunkRamContent::RawData(id, data) => {
+                ChunkBuilder::new_notype(id, ChunkData::RawData(data.to_vec()))
+            }
+            ChunkRamContent::Children(id, chunk_type, children) => ChunkBuilder::new_type(
+                id,
+                chunk_type,
+                ChunkData::ChunkList(children.into_iter().map(ChunkBuilder::from).collect()),
+            ),
+            ChunkRamContent::ChildrenNoType(id, children) => ChunkBuilder::new_notype(
+                id,
+                ChunkData::ChunkList(children.into_iter().map(ChunkBuilder::from).collect()),
+            ),
+        }
+    }
+}
+
+/// The top-level counterpart of the `ChunkRamContent -> ChunkBuilder` conversion above: reads
+/// `value` eagerly and re-wraps its children into a fresh `RiffBuilder`, so a whole `RiffRam`
+/// round-trips through the builder without the caller hand-reconstructing the tree.
+impl TryFrom<&RiffRam> for RiffBuilder {
+    type Error = RiffError;
+
+    /// Performs the conversion. Fails if `value`'s top-level chunk is not itself a `RIFF`
+    /// container, which includes the `RF64`/`BW64` files that `RiffRam::from_file` also accepts.
+    fn try_from(value: &RiffRam) -> RiffResult<Self> {
+        let content = ChunkRamContent::try_from(ChunkRam::try_from(value)?)?;
+        match content {
+            ChunkRamContent::Children(_, chunk_type, children) => {
+                let mut builder = RiffBuilder::new(chunk_type);
+                for child in children {
+                    builder = builder.add_chunk(ChunkBuilder::from(child));
+                }
+                Ok(builder)
+            }
+            _ => Err(RiffErrorKind::InvalidRiffHeader {
+                found: *value.id().as_bytes(),
+            }
+            .into()),
+        }
+    }
+}
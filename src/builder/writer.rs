@@ -0,0 +1,93 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{
+    error::RiffResult,
+    io::{Seek, SeekFrom, Write},
+    FourCC,
+};
+
+/// Streams a RIFF tree directly into a `Write + Seek` sink, backpatching each chunk's length
+/// once its payload is known instead of building the whole tree in memory first.
+///
+/// Call [`begin_chunk`](RiffWriter::begin_chunk) / [`write_data`](RiffWriter::write_data) /
+/// [`end_chunk`](RiffWriter::end_chunk) in matching pairs; chunks may be nested by calling
+/// `begin_chunk` again before the matching `end_chunk`. Unlike [`crate::builder::riff::RiffBuilder`],
+/// this never materializes the tree or a single `Vec<u8>` of the whole file — only the stack of
+/// pending length placeholders is kept in memory.
+///
+/// # Example
+///
+/// ```rust
+/// # use riffu::{builder::writer::RiffWriter, error::RiffResult, FourCC};
+/// # pub fn main() -> RiffResult<()> {
+/// let mut cursor = std::io::Cursor::new(Vec::new());
+/// let mut writer = RiffWriter::new(&mut cursor);
+/// writer.begin_chunk(&FourCC::new(b"RIFF"), Some(&FourCC::new(b"smpl")))?;
+/// writer.begin_chunk(&FourCC::new(b"test"), None)?;
+/// writer.write_data(&[255])?;
+/// writer.end_chunk()?;
+/// writer.end_chunk()?;
+/// assert_eq!(
+///     cursor.into_inner(),
+///     vec![82, 73, 70, 70, 14, 0, 0, 0, 115, 109, 112, 108, 116, 101, 115, 116, 1, 0, 0, 0, 255, 0]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct RiffWriter<W> {
+    inner: W,
+    /// Stream offset of each pending chunk's 4-byte length placeholder, one per level of nesting.
+    len_offsets: Vec<u64>,
+}
+
+impl<W: Write + Seek> RiffWriter<W> {
+    pub fn new(inner: W) -> Self {
+        RiffWriter {
+            inner,
+            len_offsets: Vec::new(),
+        }
+    }
+
+    /// Writes `id`'s FourCC followed by a placeholder length, and `chunk_type`'s FourCC right
+    /// after it when this is a `RIFF`/`LIST` container. The placeholder's offset is pushed onto
+    /// the offset stack so [`end_chunk`](RiffWriter::end_chunk) can come back and fill it in.
+    pub fn begin_chunk(&mut self, id: &FourCC, chunk_type: Option<&FourCC>) -> RiffResult<()> {
+        self.inner.write_all(id.as_bytes())?;
+        let len_offset = self.inner.seek(SeekFrom::Current(0))?;
+        self.inner.write_all(&0u32.to_le_bytes())?;
+        if let Some(chunk_type) = chunk_type {
+            self.inner.write_all(chunk_type.as_bytes())?;
+        }
+        self.len_offsets.push(len_offset);
+        Ok(())
+    }
+
+    /// Writes (part of) this chunk's payload. May be called multiple times between
+    /// `begin_chunk` and `end_chunk`.
+    pub fn write_data(&mut self, data: &[u8]) -> RiffResult<()> {
+        self.inner.write_all(data)?;
+        Ok(())
+    }
+
+    /// Closes the innermost open chunk: seeks back to its length placeholder, writes the now-known
+    /// payload length, seeks forward to the end of the stream, and emits the RIFF pad byte if the
+    /// payload length is odd.
+    pub fn end_chunk(&mut self) -> RiffResult<()> {
+        let len_offset = self
+            .len_offsets
+            .pop()
+            .expect("end_chunk called without a matching begin_chunk");
+        let end_offset = self.inner.seek(SeekFrom::Current(0))?;
+        let payload_len = end_offset - len_offset - 4;
+        if payload_len % 2 == 1 {
+            self.inner.write_all(&[0])?;
+        }
+        let after_pad_offset = self.inner.seek(SeekFrom::Current(0))?;
+        self.inner.seek(SeekFrom::Start(len_offset))?;
+        self.inner
+            .write_all(&(payload_len as u32).to_le_bytes())?;
+        self.inner.seek(SeekFrom::Start(after_pad_offset))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use bytes::Bytes;
+use std::convert::TryFrom;
+
+use crate::error::{RiffError, RiffErrorKind};
+use crate::{
+    constants::{LIST_ID, RIFF_ID, SEQT_ID},
+    error::RiffResult,
+    FourCC,
+};
+
+/// A `ChunkRam`-like reader backed by a refcounted `bytes::Bytes` buffer instead of an owned
+/// `Vec<u8>`.
+///
+/// This lets callers parse RIFF/WAVE/AVI/WebP data they already hold in memory (HTTP response
+/// bodies, mmap'd regions) with no per-chunk allocation: [`get_raw_child`](ChunkBytes::get_raw_child)
+/// hands back a `Bytes` slice that shares the backing buffer rather than copying it, unlike
+/// `ChunkRam::get_raw_child`'s `&[u8]` which still requires the whole file to be read up front.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkBytes {
+    data: Bytes,
+}
+
+impl ChunkBytes {
+    /// Returns the ASCII identifier.
+    pub fn id(&self) -> FourCC {
+        let mut buff: [u8; 4] = [0; 4];
+        buff.copy_from_slice(&self.data[..4]);
+        FourCC::new(&buff)
+    }
+
+    /// Returns the payload length.
+    pub fn payload_len(&self) -> u32 {
+        let mut buff: [u8; 4] = [0; 4];
+        buff.copy_from_slice(&self.data[4..8]);
+        u32::from_le_bytes(buff)
+    }
+
+    /// Creates a `ChunkBytes` from any `impl bytes::Buf`, copying it into an owned `Bytes` once
+    /// up front.
+    pub fn from_buf<B: bytes::Buf>(mut buf: B) -> RiffResult<ChunkBytes> {
+        let data = buf.copy_to_bytes(buf.remaining());
+        ChunkBytes::from_bytes(data)
+    }
+
+    /// Creates a `ChunkBytes` directly from an owned `Bytes`, with no copy.
+    pub fn from_bytes(data: Bytes) -> RiffResult<ChunkBytes> {
+        if data.len() >= 8 {
+            let chunk = ChunkBytes { data };
+            let declared_len = chunk.payload_len();
+            if chunk.data.len() == declared_len as usize + 8 {
+                Ok(chunk)
+            } else {
+                Err(RiffErrorKind::PayloadLenMismatch {
+                    offset: 0,
+                    declared_len,
+                    available: chunk.data.len() - 8,
+                }
+                .into())
+            }
+        } else {
+            Err(RiffErrorKind::ChunkTooSmall {
+                offset: 0,
+                needed: 8,
+                got: data.len(),
+            }
+            .into())
+        }
+    }
+
+    /// Returns the chunk type of this `ChunkBytes`, if it has one.
+    pub fn chunk_type(&self) -> RiffResult<FourCC> {
+        if self.data.len() >= 12 {
+            let mut buff: [u8; 4] = [0; 4];
+            buff.copy_from_slice(&self.data[8..12]);
+            Ok(FourCC::new(&buff))
+        } else {
+            Err(RiffErrorKind::ChunkTooSmallForChunkType {
+                offset: 0,
+                got: self.data.len(),
+            }
+            .into())
+        }
+    }
+
+    /// Returns this chunk's payload as a `Bytes` slice sharing the backing buffer, with no copy.
+    pub fn get_raw_child(&self) -> RiffResult<Bytes> {
+        let offset = match self.id().as_bytes() {
+            RIFF_ID | LIST_ID => 12,
+            _ => 8,
+        };
+        if self.data.len() >= offset {
+            Ok(self
+                .data
+                .slice(offset..offset + self.payload_len() as usize))
+        } else {
+            Err(RiffErrorKind::PayloadLenMismatch {
+                offset: 0,
+                declared_len: self.payload_len(),
+                available: self.data.len(),
+            }
+            .into())
+        }
+    }
+
+    /// Returns an iterator over this chunk's children.
+    pub fn iter(&self) -> ChunkBytesIter {
+        let offset = match self.id().as_bytes() {
+            RIFF_ID | LIST_ID => 12,
+            _ => 8,
+        };
+        ChunkBytesIter {
+            data: self.data.slice(offset..),
+            consumed: offset as u64,
+            error_occurred: false,
+        }
+    }
+}
+
+/// An iterator over the children of a `ChunkBytes`.
+#[derive(Debug)]
+pub struct ChunkBytesIter {
+    data: Bytes,
+    consumed: u64,
+    error_occurred: bool,
+}
+
+impl Iterator for ChunkBytesIter {
+    type Item = RiffResult<ChunkBytes>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error_occurred || self.data.is_empty() {
+            return None;
+        }
+        if self.data.len() < 8 {
+            self.error_occurred = true;
+            return Some(Err(RiffErrorKind::ChunkTooSmall {
+                offset: self.consumed,
+                needed: 8,
+                got: self.data.len(),
+            }
+            .into()));
+        }
+        let mut len_buff = [0u8; 4];
+        len_buff.copy_from_slice(&self.data[4..8]);
+        let payload_len = u32::from_le_bytes(len_buff) as usize;
+        if self.data.len() < 8 + payload_len {
+            self.error_occurred = true;
+            return Some(Err(RiffErrorKind::ChunkTooSmall {
+                offset: self.consumed,
+                needed: 8 + payload_len,
+                got: self.data.len(),
+            }
+            .into()));
+        }
+        let chunk_size = 8 + payload_len + (payload_len % 2);
+        let chunk_size = chunk_size.min(self.data.len());
+        let chunk_data = self.data.slice(0..8 + payload_len);
+        self.data = self.data.slice(chunk_size..);
+        self.consumed += chunk_size as u64;
+        Some(ChunkBytes::from_bytes(chunk_data))
+    }
+}
+
+/// `ChunkBytes` can be converted to a `ChunkRamContent`-like tree via `TryFrom`, mirroring
+/// `ChunkRam`'s `TryFrom<ChunkRam<'_>> for ChunkRamContent<'_>` impl, except every `RawData` leaf
+/// holds a `Bytes` instead of a borrowed slice.
+#[derive(Debug)]
+pub enum ChunkBytesContent {
+    RawData(FourCC, Bytes),
+    Children(FourCC, FourCC, Vec<ChunkBytesContent>),
+    ChildrenNoType(FourCC, Vec<ChunkBytesContent>),
+}
+
+impl TryFrom<ChunkBytes> for ChunkBytesContent {
+    type Error = RiffError;
+
+    fn try_from(chunk: ChunkBytes) -> RiffResult<Self> {
+        match chunk.id().as_bytes() {
+            RIFF_ID | LIST_ID => {
+                let chunk_type = chunk.chunk_type()?;
+                let children = chunk
+                    .iter()
+                    .map(|child| ChunkBytesContent::try_from(child?))
+                    .collect::<RiffResult<Vec<_>>>()?;
+                Ok(ChunkBytesContent::Children(chunk.id(), chunk_type, children))
+            }
+            SEQT_ID => {
+                let children = chunk
+                    .iter()
+                    .map(|child| ChunkBytesContent::try_from(child?))
+                    .collect::<RiffResult<Vec<_>>>()?;
+                Ok(ChunkBytesContent::ChildrenNoType(chunk.id(), children))
+            }
+            _ => Ok(ChunkBytesContent::RawData(
+                chunk.id(),
+                chunk.get_raw_child()?,
+            )),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! An opt-in semantic layer over [`crate::eager::riff::ChunkRam`]'s raw bytes: strongly-typed
+//! decodes for a handful of well-known leaf chunks (`fmt `, `fact`, `avih`), reached through
+//! [`crate::eager::riff::ChunkRam::parse_as`]. Chunks with an id this module doesn't know about
+//! are left as raw data; nothing here forces typing on a caller who only wants bytes.
+
+use crate::error::{RiffErrorKind, RiffResult};
+
+/// A leaf chunk payload that can be decoded from its raw bytes, keyed on the `FourCC` it expects
+/// to be read from.
+pub trait ChunkPayload: Sized {
+    /// The chunk id this payload type decodes, e.g. `b"fmt "`.
+    const ID: &'static [u8; 4];
+
+    /// Decodes `data` (the chunk's raw payload, without the id/length header) into `Self`.
+    fn decode(data: &[u8]) -> RiffResult<Self>;
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+fn require_len(data: &[u8], min_len: usize) -> RiffResult<()> {
+    if data.len() < min_len {
+        Err(RiffErrorKind::ChunkTooSmall {
+            offset: 0,
+            needed: min_len,
+            got: data.len(),
+        }
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+/// The `fmt ` chunk of a WAVE file (`WAVEFORMATEX`): the codec tag, channel layout and sample
+/// timing, plus the extension fields present when `format_tag` is `0xFFFE` (`WAVE_FORMAT_EXTENSIBLE`)
+/// or any other non-PCM tag that carries `cbSize`-prefixed extra data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaveFormat {
+    pub format_tag: u16,
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub byte_rate: u32,
+    pub block_align: u16,
+    pub bits_per_sample: u16,
+    /// The bytes following `cbSize` when the chunk is longer than the 16-byte base `PCMWAVEFORMAT`
+    /// layout. `None` for a plain PCM `fmt ` chunk.
+    pub extension: Option<Vec<u8>>,
+}
+
+impl ChunkPayload for WaveFormat {
+    const ID: &'static [u8; 4] = b"fmt ";
+
+    fn decode(data: &[u8]) -> RiffResult<Self> {
+        require_len(data, 16)?;
+        let extension = if data.len() > 16 {
+            require_len(data, 18)?;
+            let extra_size = read_u16(data, 16) as usize;
+            require_len(data, 18 + extra_size)?;
+            Some(data[18..18 + extra_size].to_vec())
+        } else {
+            None
+        };
+        Ok(WaveFormat {
+            format_tag: read_u16(data, 0),
+            channels: read_u16(data, 2),
+            sample_rate: read_u32(data, 4),
+            byte_rate: read_u32(data, 8),
+            block_align: read_u16(data, 12),
+            bits_per_sample: read_u16(data, 14),
+            extension,
+        })
+    }
+}
+
+/// The `fact` chunk, carrying the number of samples (per channel) in a `data` chunk whose encoding
+/// makes that uncomputable from its byte length alone (e.g. compressed WAVE formats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FactChunk {
+    pub sample_count: u32,
+}
+
+impl ChunkPayload for FactChunk {
+    const ID: &'static [u8; 4] = b"fact";
+
+    fn decode(data: &[u8]) -> RiffResult<Self> {
+        require_len(data, 4)?;
+        Ok(FactChunk {
+            sample_count: read_u32(data, 0),
+        })
+    }
+}
+
+/// The `avih` main AVI header, found directly inside the first `LIST` (`hdrl`) chunk of an AVI
+/// file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AviMainHeader {
+    pub micro_sec_per_frame: u32,
+    pub max_bytes_per_sec: u32,
+    pub padding_granularity: u32,
+    pub flags: u32,
+    pub total_frames: u32,
+    pub initial_frames: u32,
+    pub streams: u32,
+    pub suggested_buffer_size: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ChunkPayload for AviMainHeader {
+    const ID: &'static [u8; 4] = b"avih";
+
+    fn decode(data: &[u8]) -> RiffResult<Self> {
+        require_len(data, 40)?;
+        Ok(AviMainHeader {
+            micro_sec_per_frame: read_u32(data, 0),
+            max_bytes_per_sec: read_u32(data, 4),
+            padding_granularity: read_u32(data, 8),
+            flags: read_u32(data, 12),
+            total_frames: read_u32(data, 16),
+            initial_frames: read_u32(data, 20),
+            streams: read_u32(data, 24),
+            suggested_buffer_size: read_u32(data, 28),
+            width: read_u32(data, 32),
+            height: read_u32(data, 36),
+        })
+    }
+}
+
+/// Checks `found` against `T::ID`, returning [`RiffErrorKind::ChunkIdMismatch`] on a mismatch.
+pub(crate) fn check_id<T: ChunkPayload>(found: &[u8; 4]) -> RiffResult<()> {
+    if found == T::ID {
+        Ok(())
+    } else {
+        Err(RiffErrorKind::ChunkIdMismatch {
+            expected: *T::ID,
+            found: *found,
+        }
+        .into())
+    }
+}
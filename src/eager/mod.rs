@@ -0,0 +1,5 @@
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod formats;
+pub mod rf64;
+pub mod riff;
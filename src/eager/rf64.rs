@@ -0,0 +1,283 @@
+//! Transparent RF64/BW64 support for files whose size exceeds the 4 GiB that a plain `u32`
+//! `payload_len` can represent.
+//!
+//! A normal RIFF file's 32-bit size fields cap it at just under 4 GiB. RF64 (and its BWF sibling
+//! BW64) works around this by using the sentinel `0xFFFFFFFF` in place of the real size and
+//! storing the true 64-bit sizes in a mandatory `ds64` chunk that immediately follows the
+//! `RF64`/`BW64` header. This module only adds new, opt-in entry points
+//! ([`RiffRam::payload_len_64`], [`ChunkRam::payload_len_64`], [`ChunkRam::iter_rf64`]); files
+//! under 4 GiB keep going through the existing `payload_len`/[`ChunkRam::iter`] path untouched.
+
+use std::convert::TryFrom;
+
+use crate::{
+    eager::riff::{ChunkRam, RiffRam},
+    error::{RiffErrorKind, RiffResult},
+    FourCC,
+};
+
+const RF64_ID: &[u8; 4] = b"RF64";
+const BW64_ID: &[u8; 4] = b"BW64";
+const DS64_ID: &[u8; 4] = b"ds64";
+/// The 32-bit size sentinel RF64/BW64 uses to mean "see the `ds64` chunk instead".
+const OVERSIZED: u32 = 0xFFFFFFFF;
+
+/// The 64-bit sizes carried by a `ds64` chunk: the real RIFF payload size, the real `data` chunk
+/// size, a sample count (used by BWF for `fact`), and a table of overrides for any other chunk
+/// that also needs to report a size beyond [`OVERSIZED`].
+#[derive(Debug, Clone, Default)]
+pub struct Ds64Info {
+    pub riff_size: u64,
+    pub data_size: u64,
+    pub sample_count: u64,
+    pub table: Vec<(FourCC, u64)>,
+}
+
+impl Ds64Info {
+    /// Parses a `ds64` chunk's raw payload: three 64-bit sizes, a `u32` table length, then that
+    /// many `(FourCC, u64)` entries.
+    pub fn parse(data: &[u8]) -> RiffResult<Ds64Info> {
+        if data.len() < 28 {
+            return Err(RiffErrorKind::ChunkTooSmall {
+                offset: 0,
+                needed: 28,
+                got: data.len(),
+            }
+            .into());
+        }
+        let read_u64 = |offset: usize| -> u64 {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(buf)
+        };
+        let riff_size = read_u64(0);
+        let data_size = read_u64(8);
+        let sample_count = read_u64(16);
+        let mut table_len_buf = [0u8; 4];
+        table_len_buf.copy_from_slice(&data[24..28]);
+        let table_len = u32::from_le_bytes(table_len_buf) as usize;
+
+        let mut table = Vec::with_capacity(table_len);
+        let mut offset = 28;
+        for _ in 0..table_len {
+            if data.len() < offset + 12 {
+                break;
+            }
+            let mut id_buf = [0u8; 4];
+            id_buf.copy_from_slice(&data[offset..offset + 4]);
+            let size = read_u64(offset + 4);
+            table.push((FourCC::new(&id_buf), size));
+            offset += 12;
+        }
+
+        Ok(Ds64Info {
+            riff_size,
+            data_size,
+            sample_count,
+            table,
+        })
+    }
+
+    /// Looks up the true size of `id` in the override table (for any oversized chunk other than
+    /// the top-level RIFF size or the `data` chunk, which get their own dedicated fields).
+    pub fn lookup(&self, id: &FourCC) -> Option<u64> {
+        self.table
+            .iter()
+            .find(|(table_id, _)| table_id.as_bytes() == id.as_bytes())
+            .map(|(_, size)| *size)
+    }
+}
+
+impl RiffRam {
+    /// Returns whether this file's top-level id is `RF64` or `BW64`.
+    pub fn is_rf64(&self) -> bool {
+        matches!(self.id().as_bytes(), RF64_ID | BW64_ID)
+    }
+
+    /// Reads this file's mandatory `ds64` chunk (the chunk immediately following the header),
+    /// if this is an `RF64`/`BW64` file whose `payload_len` is the [`OVERSIZED`] sentinel.
+    pub fn ds64(&self) -> RiffResult<Option<Ds64Info>> {
+        if !self.is_rf64() || self.payload_len() != OVERSIZED {
+            return Ok(None);
+        }
+        // `ChunkRam::from_raw_u8`/`TryFrom<&RiffRam>` validate that `data.len() == declared_len
+        // + 8`, but `declared_len` here is the `OVERSIZED` sentinel itself, not this file's real
+        // byte length — that check would always fail. Read the header raw instead, the same way
+        // `iter_rf64` already does for children whose own declared length can't be trusted.
+        let data = ChunkRam::from_raw_unchecked(&self.data).raw_data();
+        if data.len() < 12 + 8 {
+            return Err(RiffErrorKind::ChunkTooSmall {
+                offset: 12,
+                needed: 8,
+                got: data.len().saturating_sub(12),
+            }
+            .into());
+        }
+        let mut id_buf = [0u8; 4];
+        id_buf.copy_from_slice(&data[12..16]);
+        let ds64_id = FourCC::new(&id_buf);
+        if ds64_id.as_bytes() != DS64_ID {
+            return Err(RiffErrorKind::ChunkIdMismatch {
+                expected: *DS64_ID,
+                found: *ds64_id.as_bytes(),
+            }
+            .into());
+        }
+        let mut len_buf = [0u8; 4];
+        len_buf.copy_from_slice(&data[16..20]);
+        let ds64_len = u32::from_le_bytes(len_buf) as usize;
+        if data.len() < 20 + ds64_len {
+            return Err(RiffErrorKind::ChunkTooSmall {
+                offset: 20,
+                needed: ds64_len,
+                got: data.len().saturating_sub(20),
+            }
+            .into());
+        }
+        Ok(Some(Ds64Info::parse(&data[20..20 + ds64_len])?))
+    }
+
+    /// Returns the true payload length, consulting the `ds64` chunk's `riff_size` field when this
+    /// file's 32-bit `payload_len` is the [`OVERSIZED`] sentinel. Files under 4 GiB keep reporting
+    /// the same value as `payload_len`.
+    pub fn payload_len_64(&self) -> RiffResult<u64> {
+        match self.ds64()? {
+            Some(ds64) => Ok(ds64.riff_size),
+            None => Ok(self.payload_len() as u64),
+        }
+    }
+
+    /// Returns the root `ChunkRam` for iterating with [`ChunkRam::iter_rf64`].
+    ///
+    /// Unlike `ChunkRam::try_from(&riff_ram)`, this doesn't run the file through
+    /// `ChunkRam::from_raw_u8`'s strict `data.len() == declared_len + 8` check, which a real
+    /// `RF64`/`BW64` file always fails (its declared length is the [`OVERSIZED`] sentinel, not
+    /// its actual byte count).
+    pub fn root_chunk_rf64(&self) -> ChunkRam<'_> {
+        ChunkRam::from_raw_unchecked(&self.data)
+    }
+}
+
+impl<'a> ChunkRam<'a> {
+    /// Returns the true payload length of this chunk, consulting `ds64` when `payload_len` is the
+    /// [`OVERSIZED`] sentinel: the dedicated `data_size` field for a `data` chunk, or the override
+    /// table for anything else.
+    pub fn payload_len_64(&self, ds64: &Ds64Info) -> u64 {
+        if self.payload_len() != OVERSIZED {
+            return self.payload_len() as u64;
+        }
+        if self.id().as_bytes() == b"data" {
+            return ds64.data_size;
+        }
+        ds64.lookup(&self.id()).unwrap_or(OVERSIZED as u64)
+    }
+
+    /// Like [`ChunkRam::iter`], but steps over an oversized child (`payload_len` equal to the
+    /// [`OVERSIZED`] sentinel) using [`payload_len_64`](ChunkRam::payload_len_64) instead of
+    /// misreading the sentinel itself as a 4 GiB-ish length — which is what [`ChunkRam::iter`]
+    /// would do, since it only ever trusts the embedded 32-bit field. On an `RF64`/`BW64` root,
+    /// the leading `ds64` chunk is skipped rather than yielded.
+    pub fn iter_rf64(&self, ds64: &'a Ds64Info) -> ChunkRamIterRf64<'a> {
+        // `RF64`/`BW64` carry a mandatory chunk_type just like `RIFF`/`LIST`, so they need the
+        // same 12-byte header skip — only a typeless leaf/`seqt` container uses 8.
+        let header_len = match self.id().as_bytes() {
+            b"RIFF" | b"LIST" | RF64_ID | BW64_ID => 12,
+            _ => 8,
+        };
+        let data = &self.raw_data()[header_len..];
+        // The `ds64` chunk leading an `RF64`/`BW64` body holds the sizes `ds64` itself was parsed
+        // from, so iteration starts after it. A truncated `ds64` is not skipped, so the iterator
+        // reports it as too small.
+        let cursor = match self.id().as_bytes() {
+            RF64_ID | BW64_ID if data.len() >= 8 && &data[..4] == DS64_ID => {
+                let len = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+                if len <= data.len() - 8 {
+                    (8 + len + len % 2).min(data.len())
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        };
+        ChunkRamIterRf64 {
+            cursor,
+            data,
+            ds64,
+            error_occurred: false,
+        }
+    }
+}
+
+/// An iterator over the children of an RF64/BW64 container, consulting `ds64` to recover a
+/// child's true size whenever its 32-bit `payload_len` is the [`OVERSIZED`] sentinel. See
+/// [`ChunkRam::iter_rf64`].
+pub struct ChunkRamIterRf64<'a> {
+    cursor: usize,
+    data: &'a [u8],
+    ds64: &'a Ds64Info,
+    error_occurred: bool,
+}
+
+impl<'a> Iterator for ChunkRamIterRf64<'a> {
+    type Item = RiffResult<ChunkRam<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error_occurred || self.cursor == self.data.len() {
+            return None;
+        }
+        if self.data.len() < self.cursor + 8 {
+            self.error_occurred = true;
+            return Some(Err(RiffErrorKind::ChunkTooSmall {
+                offset: self.cursor as u64,
+                needed: 8,
+                got: self.data.len() - self.cursor,
+            }
+            .into()));
+        }
+
+        let mut id_buf = [0u8; 4];
+        id_buf.copy_from_slice(&self.data[self.cursor..self.cursor + 4]);
+        let id = FourCC::new(&id_buf);
+        let mut len_buf = [0u8; 4];
+        len_buf.copy_from_slice(&self.data[self.cursor + 4..self.cursor + 8]);
+        let declared_len = u32::from_le_bytes(len_buf);
+
+        // Mirrors `ChunkRam::payload_len_64`'s two special cases; duplicated rather than reused
+        // because that method needs an already-built `ChunkRam`, and we can't build one until we
+        // know the true length it should span.
+        let true_len = if declared_len != OVERSIZED {
+            declared_len as u64
+        } else if id.as_bytes() == b"data" {
+            self.ds64.data_size
+        } else {
+            self.ds64.lookup(&id).unwrap_or(OVERSIZED as u64)
+        };
+
+        let end = match usize::try_from(true_len)
+            .ok()
+            .and_then(|true_len| self.cursor.checked_add(8)?.checked_add(true_len))
+        {
+            Some(end) => end,
+            None => {
+                self.error_occurred = true;
+                return Some(Err(RiffErrorKind::LengthOverflow {
+                    declared_len,
+                }
+                .into()));
+            }
+        };
+        if self.data.len() < end {
+            self.error_occurred = true;
+            return Some(Err(RiffErrorKind::ChunkTooSmall {
+                offset: self.cursor as u64,
+                needed: end - self.cursor,
+                got: self.data.len() - self.cursor,
+            }
+            .into()));
+        }
+
+        let chunk = ChunkRam::from_raw_unchecked(&self.data[self.cursor..end]);
+        self.cursor = end + (true_len as usize % 2);
+        Some(Ok(chunk))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::{ChunkTooSmall, ChunkTooSmallForChunkType, PayloadLenMismatch, RiffError};
+use crate::error::{RiffError, RiffErrorKind};
 use crate::{
     constants::{LIST_ID, RIFF_ID, SEQT_ID},
     error::RiffResult,
@@ -116,13 +116,20 @@ impl RiffRam {
             let mut id_buff: [u8; 4] = [0; 4];
             id_buff.copy_from_slice(&data[0..4]);
             let id = FourCC { data: id_buff };
-            if id.as_str()? == RIFF_ID {
-                Ok(RiffRam { data })
-            } else {
-                Err(RiffError::InvalidRiffHeader)
+            // `RF64`/`BW64` are large-file variants of `RIFF` (see `crate::eager::rf64`); their
+            // real size lives in a `ds64` chunk instead of this header's own length field, but
+            // the header itself is still valid.
+            match id.as_str()? {
+                RIFF_ID | "RF64" | "BW64" => Ok(RiffRam { data }),
+                _ => Err(RiffErrorKind::InvalidRiffHeader { found: id_buff }.into()),
             }
         } else {
-            Err(RiffError::ChunkTooSmall(ChunkTooSmall { data }))
+            Err(RiffErrorKind::ChunkTooSmall {
+                offset: 0,
+                needed: 8,
+                got: data.len(),
+            }
+            .into())
         }
     }
 
@@ -176,17 +183,24 @@ impl<'a> ChunkRam<'a> {
         if data.len() >= 8 {
             let chunk = ChunkRam { data: &data };
             // Guarantee that the data given is able to satisfy the payload length provided.
-            if data.len() == chunk.payload_len() as usize + 8 {
+            let declared_len = chunk.payload_len();
+            if data.len() == declared_len as usize + 8 {
                 Ok(chunk)
             } else {
-                Err(RiffError::PayloadLenMismatch(PayloadLenMismatch {
-                    data: Vec::from(data),
-                }))
+                Err(RiffErrorKind::PayloadLenMismatch {
+                    offset: 0,
+                    declared_len,
+                    available: data.len() - 8,
+                }
+                .into())
             }
         } else {
-            Err(RiffError::ChunkTooSmall(ChunkTooSmall {
-                data: Vec::from(data),
-            }))
+            Err(RiffErrorKind::ChunkTooSmall {
+                offset: 0,
+                needed: 8,
+                got: data.len(),
+            }
+            .into())
         }
     }
 
@@ -199,14 +213,30 @@ impl<'a> ChunkRam<'a> {
             buff.copy_from_slice(&self.data[8..12]);
             Ok(FourCC { data: buff })
         } else {
-            Err(RiffError::ChunkTooSmallForChunkType(
-                ChunkTooSmallForChunkType {
-                    data: Vec::from(self.data),
-                },
-            ))
+            Err(RiffErrorKind::ChunkTooSmallForChunkType {
+                offset: 0,
+                got: self.data.len(),
+            }
+            .into())
         }
     }
 
+    /// Exposes this chunk's raw backing bytes (header, optional chunk type, and payload) to
+    /// [`crate::eager::rf64`], which needs to re-slice past a child whose declared 32-bit
+    /// `payload_len` is the RF64 `OVERSIZED` sentinel — something [`ChunkRam::get_raw_child`] and
+    /// [`ChunkRam::iter`] can't do, since both trust that field.
+    pub(crate) fn raw_data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Builds a `ChunkRam` over `data` without checking that its declared `payload_len` matches
+    /// `data.len() - 8`, for [`crate::eager::rf64`] to construct a child whose true size (read
+    /// from a `ds64` chunk) differs from the on-disk `OVERSIZED` sentinel that
+    /// [`ChunkRam::from_raw_u8`] would otherwise reject.
+    pub(crate) fn from_raw_unchecked(data: &'a [u8]) -> ChunkRam<'a> {
+        ChunkRam { data }
+    }
+
     /// Returns the data that this `ChunkRam` hold as raw array of bytes.
     pub fn get_raw_child(&self) -> RiffResult<&'a [u8]> {
         let offset = match self.id().as_str() {
@@ -218,12 +248,44 @@ impl<'a> ChunkRam<'a> {
         if self.data.len() >= offset {
             Ok(&self.data[offset..offset + self.payload_len() as usize])
         } else {
-            Err(RiffError::PayloadLenMismatch(PayloadLenMismatch {
-                data: Vec::from(self.data),
-            }))
+            Err(RiffErrorKind::PayloadLenMismatch {
+                offset: 0,
+                declared_len: self.payload_len(),
+                available: self.data.len(),
+            }
+            .into())
         }
     }
 
+    /// Decodes this chunk's payload as a strongly-typed `T` (e.g. [`crate::eager::formats::WaveFormat`]
+    /// from a `fmt ` chunk), keyed on `T::ID`. Returns [`RiffErrorKind::ChunkIdMismatch`] if this
+    /// chunk's id doesn't match, or a decode error if the payload is too short for `T`'s layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use riffu::{error::RiffResult, eager::{riff::ChunkRam, formats::WaveFormat}};
+    /// # pub fn main() -> RiffResult<()> {
+    /// let mut data = b"fmt ".to_vec();
+    /// data.extend_from_slice(&16u32.to_le_bytes()); // payload_len
+    /// data.extend_from_slice(&1u16.to_le_bytes()); // format_tag (PCM)
+    /// data.extend_from_slice(&2u16.to_le_bytes()); // channels
+    /// data.extend_from_slice(&44100u32.to_le_bytes()); // sample_rate
+    /// data.extend_from_slice(&176400u32.to_le_bytes()); // byte_rate
+    /// data.extend_from_slice(&4u16.to_le_bytes()); // block_align
+    /// data.extend_from_slice(&16u16.to_le_bytes()); // bits_per_sample
+    ///
+    /// let fmt_chunk = ChunkRam::from_raw_u8(&data)?;
+    /// let format: WaveFormat = fmt_chunk.parse_as()?;
+    /// assert_eq!(format.channels, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_as<T: crate::eager::formats::ChunkPayload>(&self) -> RiffResult<T> {
+        crate::eager::formats::check_id::<T>(self.id().as_bytes())?;
+        T::decode(self.get_raw_child()?)
+    }
+
     /// Returns an iterator over the data of this `ChunkRam`.
     pub fn iter(&self) -> ChunkRamIter<'a> {
         match self.id().as_str() {
@@ -241,7 +303,7 @@ impl<'a> ChunkRam<'a> {
     }
 }
 
-fn payload_length(data: &[u8]) -> RiffResult<u32> {
+fn payload_length(data: &[u8], offset: u64) -> RiffResult<u32> {
     if data.len() >= 8 {
         let mut buff: [u8; 4] = [0; 4];
         // SAFETY: Any creation of `ChunkRam` must occur through `ChunkRam::from_raw_u8`.
@@ -249,10 +311,12 @@ fn payload_length(data: &[u8]) -> RiffResult<u32> {
         buff.copy_from_slice(&data[4..8]);
         Ok(u32::from_le_bytes(buff))
     } else {
-        // Should probably be an error specific to the data begin too small.
-        Err(RiffError::ChunkTooSmall(ChunkTooSmall {
-            data: Vec::from(data),
-        }))
+        Err(RiffErrorKind::ChunkTooSmall {
+            offset,
+            needed: 8,
+            got: data.len(),
+        }
+        .into())
     }
 }
 
@@ -274,7 +338,7 @@ impl<'a> Iterator for ChunkRamIter<'a> {
             None
         } else {
             let cursor = self.cursor as usize;
-            match payload_length(&self.data[cursor..]) {
+            match payload_length(&self.data[cursor..], self.cursor as u64) {
                 Ok(payload_len) => {
                     let payload_len = payload_len as usize;
                     if self.data.len() >= cursor + 8 + payload_len {
@@ -293,9 +357,12 @@ impl<'a> Iterator for ChunkRamIter<'a> {
                         }
                     } else {
                         self.error_occurred = true;
-                        Some(Err(RiffError::ChunkTooSmall(ChunkTooSmall {
-                            data: Vec::from(&self.data[cursor..]),
-                        })))
+                        Some(Err(RiffErrorKind::ChunkTooSmall {
+                            offset: self.cursor as u64,
+                            needed: 8 + payload_len,
+                            got: self.data.len() - cursor,
+                        }
+                        .into()))
                     }
                 }
                 Err(err) => {
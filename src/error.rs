@@ -1,92 +1,237 @@
+#[cfg(feature = "std")]
 use std::fmt::Formatter;
 
-/// The type of errors that this library may emit.
-/// Note that most of this errors are currently unused.
-/// There are many, many ways reading into a RIFF file may fail.
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use core::fmt::Formatter;
+
+use crate::FourCC;
+
+/// The specific kind of error that [`RiffError`] wraps.
+///
+/// Marked `#[non_exhaustive]` so new diagnostic variants can be added without a breaking change;
+/// match on this with a wildcard arm.
 #[derive(Debug)]
-pub enum RiffError {
-    /// Indicates that the provided payload length does not match the raw data's length.
-    /// Since the data may be a list of `Chunk`s, it is more likely that this error is caused when payload's length > raw data's size.
-    PayloadLenMismatch(PayloadLenMismatch),
-    /// Indicates that the requested data is too small to be a valid chunk.
-    /// Note that this returns the entire data and the starting position where this "chunk" is supposed to reside.
-    ChunkTooSmall(ChunkTooSmall),
-    /// Indicates that the `Chunk` is too small to contain a `FourCC`.
-    ChunkTooSmallForChunkType(ChunkTooSmallForChunkType),
-    /// Indicates that this is a malformed RIFF file.
-    /// RIFF file requires that the first 4 bytes of the file contains the ASCII letters "RIFF".
-    InvalidRiffHeader,
+#[non_exhaustive]
+pub enum RiffErrorKind {
+    /// The payload length declared in a chunk's header doesn't match the amount of data actually
+    /// backing it.
+    PayloadLenMismatch {
+        /// Byte offset, within the buffer the chunk was parsed from, where the chunk starts.
+        offset: u64,
+        /// The `u32` payload length read from the chunk's header.
+        declared_len: u32,
+        /// The number of bytes actually available after the header at `offset`.
+        available: usize,
+    },
+    /// Fewer bytes were available at `offset` than are needed to hold what is being read there:
+    /// a chunk header, a whole chunk, or a payload's fixed layout.
+    ChunkTooSmall {
+        /// Byte offset, within the buffer the chunk was parsed from, where the chunk starts.
+        offset: u64,
+        /// The number of bytes what is being read at this position needs.
+        needed: usize,
+        /// The number of bytes actually available at `offset`.
+        got: usize,
+    },
+    /// The chunk at `offset` is too short to contain the 4-byte chunk type that `RIFF`/`LIST`
+    /// chunks carry right after their length field.
+    ChunkTooSmallForChunkType {
+        /// Byte offset, within the buffer the chunk was parsed from, where the chunk starts.
+        offset: u64,
+        /// The number of bytes actually available at `offset`.
+        got: usize,
+    },
+    /// The first 4 bytes of a file were expected to be the ASCII letters `RIFF` (or an RF64/BW64
+    /// variant) but were something else.
+    InvalidRiffHeader {
+        /// The 4 bytes that were found in place of a valid RIFF-family identifier.
+        found: [u8; 4],
+    },
+    /// A child chunk was located at an offset other than where the parent's bookkeeping expected
+    /// it to be.
+    InvalidChunkOffset {
+        chunk_id: FourCC,
+        expected: u64,
+        actual: u64,
+    },
     /// Indicates an attempt at appending a raw chunk into a chunk with
     MismatchChunkAdded,
-    Other(Box<dyn std::error::Error>),
+    /// Indicates that a `FourCC` was built from, or validated against, bytes outside the
+    /// printable-ASCII range (`0x20`-`0x7E`) the RIFF spec allows for identifiers.
+    InvalidFourCC {
+        /// The 4 bytes that failed validation.
+        bytes: [u8; 4],
+        /// Where `bytes` came from: a byte offset for a `FourCC` read out of a larger buffer, or
+        /// `0` when validating a standalone `FourCC` with no such context (e.g. [`crate::FourCC::validate`]).
+        position: u64,
+    },
+    /// Indicates that [`crate::eager::riff::ChunkRam::parse_as`] was called against a chunk whose
+    /// id doesn't match the requested payload type (e.g. parsing a `fmt ` chunk as a `FactChunk`).
+    ChunkIdMismatch { expected: [u8; 4], found: [u8; 4] },
+    /// A chunk's declared length, plus its 8-byte header and (for odd lengths) its pad byte,
+    /// overflows when computing the total framed size (`u32` in [`crate::parser`], the `usize`
+    /// end offset when stepping over an RF64 child).
+    LengthOverflow {
+        /// The payload length that, combined with the header and pad byte, overflowed.
+        declared_len: u32,
+    },
+    Other(OtherError),
 }
 
-#[derive(Debug)]
-pub struct ChunkTooSmallForChunkType {
-    pub(crate) data: Vec<u8>,
+/// The type-erased payload of [`RiffErrorKind::Other`]. Under `std`, this is `Error`-bound so
+/// [`RiffError::source`] can hand back the wrapped error; under `no_std`, `core::error::Error`
+/// isn't available, so it falls back to only requiring `Debug`.
+#[cfg(feature = "std")]
+pub type OtherError = Box<dyn std::error::Error + Send + Sync>;
+#[cfg(not(feature = "std"))]
+pub type OtherError = Box<dyn core::fmt::Debug + Send + Sync>;
+
+impl core::fmt::Display for RiffErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RiffErrorKind::PayloadLenMismatch {
+                offset,
+                declared_len,
+                available,
+            } => write!(
+                f,
+                "chunk at offset {} declares a payload length of {} bytes, but only {} bytes are available",
+                offset, declared_len, available
+            ),
+            RiffErrorKind::ChunkTooSmall { offset, needed, got } => write!(
+                f,
+                "chunk at offset {} needs at least {} bytes, but only {} bytes are available",
+                offset, needed, got
+            ),
+            RiffErrorKind::ChunkTooSmallForChunkType { offset, got } => write!(
+                f,
+                "chunk at offset {} is too small to contain a chunk type (12 bytes needed, {} available)",
+                offset, got
+            ),
+            RiffErrorKind::InvalidRiffHeader { found } => write!(
+                f,
+                "expected a RIFF-family identifier (RIFF, RF64, BW64) as the first 4 bytes, found {:?}",
+                found
+            ),
+            RiffErrorKind::InvalidChunkOffset {
+                chunk_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "chunk {:?} was expected at offset {} but found at offset {}",
+                chunk_id, expected, actual
+            ),
+            RiffErrorKind::MismatchChunkAdded => {
+                write!(f, "attempted to add a chunk to a chunk holding raw data")
+            }
+            RiffErrorKind::InvalidFourCC { bytes, position } => write!(
+                f,
+                "{:?} at offset {} is not a valid FourCC (bytes must be printable ASCII, 0x20-0x7E)",
+                bytes, position
+            ),
+            RiffErrorKind::ChunkIdMismatch { expected, found } => write!(
+                f,
+                "expected a chunk with id {:?}, found {:?}",
+                expected, found
+            ),
+            RiffErrorKind::LengthOverflow { declared_len } => write!(
+                f,
+                "declared payload length {} overflows when combined with its header and pad byte",
+                declared_len
+            ),
+            #[cfg(feature = "std")]
+            RiffErrorKind::Other(err) => write!(f, "{}", err),
+            #[cfg(not(feature = "std"))]
+            RiffErrorKind::Other(err) => write!(f, "{:?}", err),
+        }
+    }
 }
 
-impl std::fmt::Display for ChunkTooSmallForChunkType {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self)
+#[cfg(feature = "std")]
+impl std::error::Error for RiffErrorKind {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RiffErrorKind::Other(err) => Some(err.as_ref()),
+            _ => None,
+        }
     }
 }
 
+/// The error type that this library emits.
+///
+/// Boxes its [`RiffErrorKind`] payload so `size_of::<RiffError>()` stays a single pointer instead
+/// of the worst-case variant's size, keeping `RiffResult<T>`'s `Ok` path cheap (see clippy's
+/// `result_large_err` lint, which this sidesteps). Use [`RiffError::kind`] to inspect what went
+/// wrong.
 #[derive(Debug)]
-pub struct ChunkTooSmall {
-    pub(crate) data: Vec<u8>,
-}
+pub struct RiffError(Box<RiffErrorKind>);
+
+impl RiffError {
+    /// Returns the specific kind of error that occurred.
+    pub fn kind(&self) -> &RiffErrorKind {
+        &self.0
+    }
 
-impl std::fmt::Display for ChunkTooSmall {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self)
+    /// Unwraps this error into its [`RiffErrorKind`], discarding the box.
+    pub fn into_kind(self) -> RiffErrorKind {
+        *self.0
     }
 }
 
-#[derive(Debug)]
-pub struct PayloadLenMismatch {
-    pub(crate) data: Vec<u8>,
+impl From<RiffErrorKind> for RiffError {
+    fn from(kind: RiffErrorKind) -> Self {
+        RiffError(Box::new(kind))
+    }
 }
 
-impl std::fmt::Display for PayloadLenMismatch {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self)
+impl core::fmt::Display for RiffError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for RiffError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(self)
+        std::error::Error::source(self.0.as_ref())
     }
 }
 
-impl std::fmt::Display for RiffError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self)
+/// Converts `std::io::Error`. Only available with the `std` feature; under `no_std`, use
+/// [`core_io`]'s `Error` via the `no_std` conversion below instead.
+#[cfg(feature = "std")]
+impl From<std::io::Error> for RiffError {
+    /// Performs the conversion.
+    fn from(v: std::io::Error) -> Self {
+        RiffErrorKind::Other(Box::new(v)).into()
     }
 }
 
-/// Converts `std::io::Error`.
-impl From<std::io::Error> for RiffError {
+/// Converts `core_io::Error` when the `std` feature is disabled.
+#[cfg(not(feature = "std"))]
+impl From<core_io::Error> for RiffError {
     /// Performs the conversion.
-    fn from(v: std::io::Error) -> Self {
-        RiffError::Other(Box::new(v))
+    fn from(v: core_io::Error) -> Self {
+        RiffErrorKind::Other(Box::new(v)).into()
     }
 }
 
 /// Converts `std::str::Utf8Error`.
-impl From<std::str::Utf8Error> for RiffError {
+impl From<core::str::Utf8Error> for RiffError {
     /// Performs the conversion.
-    fn from(v: std::str::Utf8Error) -> Self {
-        RiffError::Other(Box::new(v))
+    fn from(v: core::str::Utf8Error) -> Self {
+        RiffErrorKind::Other(Box::new(v)).into()
     }
 }
 
 /// Converts `std::option::NoneError`.
-impl From<std::array::TryFromSliceError> for RiffError {
+impl From<core::array::TryFromSliceError> for RiffError {
     /// Performs the conversion.
-    fn from(v: std::array::TryFromSliceError) -> Self {
-        RiffError::Other(Box::new(v))
+    fn from(v: core::array::TryFromSliceError) -> Self {
+        RiffErrorKind::Other(Box::new(v)).into()
     }
 }
 
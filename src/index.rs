@@ -0,0 +1,115 @@
+//! A Bloom filter index over the `FourCC` ids seen in a RIFF tree, for cheaply ruling out
+//! subtrees that cannot contain a given chunk id before recursing into them.
+//!
+//! Membership only asserts that an id was *possibly* seen somewhere in the scanned region: a
+//! `true` answer from [`FourCCIndex::may_contain`] can be a false positive, but a `false` answer
+//! is never a false negative.
+
+use std::hash::Hasher;
+
+use crate::{
+    constants::{LIST_ID, RIFF_ID, SEQT_ID},
+    error::RiffResult,
+    lazy::riff::ChunkDisk,
+    FourCC,
+};
+use std::io::{Read, Seek};
+
+/// Number of `u64` words backing the bit array. `m = WORDS * 64` bits total.
+const WORDS: usize = 128;
+/// Number of independent hash functions used per id (the `k` of a standard Bloom filter).
+const HASHES: usize = 4;
+
+/// A Bloom filter keyed on 4-byte `FourCC` ids, used to cheaply rule out whole subtrees during a
+/// search like [`find_all`](FourCCIndex::find_all) before walking into them.
+#[derive(Debug, Clone)]
+pub struct FourCCIndex {
+    bits: [u64; WORDS],
+}
+
+impl FourCCIndex {
+    pub fn new() -> Self {
+        FourCCIndex { bits: [0; WORDS] }
+    }
+
+    /// Builds an index over every `FourCC` id seen in a shallow scan of `chunk`'s subtree.
+    pub fn build<R: Read + Seek>(chunk: &mut ChunkDisk<R>) -> RiffResult<FourCCIndex> {
+        let mut index = FourCCIndex::new();
+        index.scan(chunk)?;
+        Ok(index)
+    }
+
+    fn scan<R: Read + Seek>(&mut self, chunk: &mut ChunkDisk<R>) -> RiffResult<()> {
+        let id = chunk.id()?;
+        self.insert(id.as_bytes());
+        if matches!(id.as_bytes(), RIFF_ID | LIST_ID | SEQT_ID) {
+            for child in chunk.iter()? {
+                self.scan(&mut child?)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks `id` as present, setting one bit per hash function.
+    pub fn insert(&mut self, id: &[u8; 4]) {
+        for i in 0..HASHES {
+            let bit = self.bit_index(id, i);
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns `false` only if `id` is definitely not present; `true` means "possibly present".
+    pub fn may_contain(&self, id: &[u8; 4]) -> bool {
+        (0..HASHES).all(|i| {
+            let bit = self.bit_index(id, i);
+            self.bits[bit / 64] & (1 << (bit % 64)) != 0
+        })
+    }
+
+    fn bit_index(&self, id: &[u8; 4], salt: usize) -> usize {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        hasher.write_usize(salt);
+        hasher.write(id);
+        (hasher.finish() % (WORDS as u64 * 64)) as usize
+    }
+
+    /// Recursively collects every chunk under `chunk` whose id is `id`. Returns without walking
+    /// the tree when the index rules `id` out; the index covers the whole scanned tree, not
+    /// individual subtrees, so otherwise every subtree is walked.
+    pub fn find_all<R: Read + Seek>(
+        &self,
+        chunk: &mut ChunkDisk<R>,
+        id: &[u8; 4],
+    ) -> RiffResult<Vec<FourCC>> {
+        let mut results = Vec::new();
+        self.find_all_into(chunk, id, &mut results)?;
+        Ok(results)
+    }
+
+    fn find_all_into<R: Read + Seek>(
+        &self,
+        chunk: &mut ChunkDisk<R>,
+        id: &[u8; 4],
+        results: &mut Vec<FourCC>,
+    ) -> RiffResult<()> {
+        if !self.may_contain(id) {
+            return Ok(());
+        }
+        let chunk_id = chunk.id()?;
+        if chunk_id.as_bytes() == id {
+            results.push(chunk_id.clone());
+        }
+        if matches!(chunk_id.as_bytes(), RIFF_ID | LIST_ID | SEQT_ID) {
+            for child in chunk.iter()? {
+                self.find_all_into(&mut child?, id, results)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for FourCCIndex {
+    fn default() -> Self {
+        FourCCIndex::new()
+    }
+}
@@ -0,0 +1,12 @@
+//! IO trait re-exports used throughout the crate.
+//!
+//! With the default `std` feature, these are plain aliases for `std::io`. When `std` is
+//! disabled, they instead come from `core_io`, the `#![no_std]` port of `std::io`, so that the
+//! `lazy` reader and the builder can run on embedded targets (e.g. behind `fatfs` on firmware)
+//! that have an allocator but no `std`.
+
+#[cfg(feature = "std")]
+pub use std::io::{Read, Seek, SeekFrom, Write};
+
+#[cfg(not(feature = "std"))]
+pub use core_io::{Read, Seek, SeekFrom, Write};
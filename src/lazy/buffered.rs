@@ -0,0 +1,153 @@
+//! Zero-copy chunk header access over a buffered window, borrowed from proxmox's
+//! `BufferedReader`/`map_struct` approach: a reader exposes a slice into its own internal
+//! buffer instead of filling a caller-supplied one, and headers are read by validating and
+//! reinterpreting a prefix of that slice rather than copying it field-by-field.
+
+use core::mem::size_of;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use crate::{
+    error::{RiffErrorKind, RiffResult},
+    io::{Read, Seek, SeekFrom},
+    FourCC,
+};
+
+/// A reader that can hand back a slice of its own internal buffer instead of copying into one
+/// the caller provides.
+pub trait BufferedReader {
+    /// Returns a slice of this reader's internal buffer starting at `offset`, refilling from the
+    /// backing source first if fewer than `len` bytes past `offset` are already covered by what's
+    /// buffered. The returned slice holds at least `len` bytes unless `offset` is close enough to
+    /// genuine EOF that the source can't supply that many, in which case it holds whatever's left
+    /// (empty if `offset` is at or past EOF).
+    ///
+    /// `len` only bounds how much is guaranteed to be buffered going in; it isn't a cap on the
+    /// slice returned, which may run to the end of the current window.
+    fn buffered_read(&mut self, offset: u64, len: usize) -> RiffResult<&[u8]>;
+}
+
+/// The raw on-disk layout of a chunk header: a 4-byte `FourCC` id followed by a 4-byte
+/// little-endian payload length. Every field is a byte array (alignment 1), so reinterpreting a
+/// validated slice as `&ChunkHeader` can never be unaligned or read padding.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkHeader {
+    id: [u8; 4],
+    len: [u8; 4],
+}
+
+impl ChunkHeader {
+    /// The chunk's ASCII identifier.
+    pub fn id(&self) -> FourCC {
+        FourCC::new(&self.id)
+    }
+
+    /// The chunk's payload length.
+    pub fn payload_len(&self) -> u32 {
+        u32::from_le_bytes(self.len)
+    }
+}
+
+/// Validates that `data` holds at least `size_of::<ChunkHeader>()` bytes, then reinterprets its
+/// first 8 bytes in place as a `&ChunkHeader`, with no copy and no allocation.
+///
+/// Returns [`RiffErrorKind::ChunkTooSmall`] rather than panicking when `data` is too short.
+pub fn map_struct(data: &[u8]) -> RiffResult<&ChunkHeader> {
+    let needed = size_of::<ChunkHeader>();
+    if data.len() < needed {
+        return Err(RiffErrorKind::ChunkTooSmall {
+            offset: 0,
+            needed,
+            got: data.len(),
+        }
+        .into());
+    }
+    // SAFETY: `ChunkHeader` is `#[repr(C)]` over two byte arrays (alignment 1, no padding), and
+    // `data` was just checked to hold at least `size_of::<ChunkHeader>()` bytes.
+    Ok(unsafe { &*(data.as_ptr() as *const ChunkHeader) })
+}
+
+/// A [`BufferedReader`] over any `Read + Seek` source, sliding a fixed-capacity window over it
+/// and reusing whatever's already buffered when consecutive reads stay within it.
+#[derive(Debug)]
+pub struct SliceBuffer<R> {
+    reader: R,
+    buf: Vec<u8>,
+    /// The absolute offset `buf[0]` corresponds to.
+    buf_start: u64,
+    /// How many of `buf`'s bytes hold real data read from `reader` (less than `buf.len()` only
+    /// at EOF).
+    filled: usize,
+}
+
+impl<R: Read + Seek> SliceBuffer<R> {
+    /// How many bytes of lookahead each refill reads from `reader`.
+    const CAPACITY: usize = 64 * 1024;
+
+    /// Wraps `reader` in a `SliceBuffer` with an empty window; the first `buffered_read` call
+    /// fills it.
+    pub fn new(reader: R) -> Self {
+        SliceBuffer {
+            reader,
+            buf: vec![0; Self::CAPACITY],
+            buf_start: 0,
+            filled: 0,
+        }
+    }
+
+    /// Whether the window already covers at least `len` bytes starting at `offset`, or covers
+    /// everything there is (the last [`refill`](Self::refill) hit genuine EOF before filling the
+    /// whole window, so there's nothing more a re-refill at `offset` could supply).
+    fn in_window(&self, offset: u64, len: usize) -> bool {
+        if offset < self.buf_start || offset > self.buf_start + self.filled as u64 {
+            return false;
+        }
+        let available = self.buf_start + self.filled as u64 - offset;
+        available >= len as u64 || self.filled < self.buf.len()
+    }
+
+    fn refill(&mut self, offset: u64) -> RiffResult<()> {
+        self.reader.seek(SeekFrom::Start(offset))?;
+        let mut filled = 0;
+        while filled < self.buf.len() {
+            let read = self.reader.read(&mut self.buf[filled..])?;
+            if read == 0 {
+                break;
+            }
+            filled += read;
+        }
+        self.buf_start = offset;
+        self.filled = filled;
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> BufferedReader for SliceBuffer<R> {
+    /// # Example
+    ///
+    /// ```rust
+    /// # use core::mem::size_of;
+    /// # use riffu::{error::RiffResult, lazy::buffered::{ChunkHeader, SliceBuffer, BufferedReader, map_struct}};
+    /// # pub fn main() -> RiffResult<()> {
+    /// let data: Vec<u8> = vec![
+    ///     82, 73, 70, 70, 14, 0, 0, 0, 115, 109, 112, 108, 116, 101, 115, 116, 1, 0, 0, 0, 255, 0,
+    /// ];
+    /// let mut buf = SliceBuffer::new(std::io::Cursor::new(data));
+    /// let header = map_struct(buf.buffered_read(0, size_of::<ChunkHeader>())?)?;
+    /// assert_eq!(header.id().as_bytes(), b"RIFF");
+    /// assert_eq!(header.payload_len(), 14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn buffered_read(&mut self, offset: u64, len: usize) -> RiffResult<&[u8]> {
+        if !self.in_window(offset, len) {
+            self.refill(offset)?;
+        }
+        let rel = (offset - self.buf_start) as usize;
+        Ok(&self.buf[rel..self.filled])
+    }
+}
@@ -0,0 +1,4 @@
+pub mod buffered;
+pub mod riff;
+#[cfg(feature = "std")]
+pub mod sync;
@@ -1,28 +1,37 @@
-use std::{cell::RefCell, io::BufReader};
-use std::{fmt::Debug, fs::File, rc::Rc};
-use std::{
-    io::{Read, Seek},
-    path::Path,
-};
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+#[cfg(feature = "std")]
+use std::rc::Rc;
+
+#[cfg(not(feature = "std"))]
+use core::cell::RefCell;
+
+#[cfg(feature = "std")]
+use std::{fs::File, io::BufReader, path::Path};
 
 use crate::{
     constants::{LIST_ID, RIFF_ID, SEQT_ID},
     error::RiffResult,
+    io::{Read, Seek, SeekFrom},
     FourCC,
 };
 
-type RcReader = std::rc::Rc<RefCell<BufReader<std::fs::File>>>;
+/// The reader that backs a `ChunkDisk<R>`, shared by every chunk and sub-chunk handle that was
+/// derived from the same source.
+type RcReader<R> = Rc<RefCell<R>>;
 
 /// Represents the possible data contained in a `ChunkDisk`.
 #[derive(Debug)]
-pub enum ChunkDiskType {
-    RawData(ChunkDisk),
-    Children(ChunkDisk),
-    ChildrenNoType(ChunkDisk),
+pub enum ChunkDiskType<R> {
+    RawData(ChunkDisk<R>),
+    Children(ChunkDisk<R>),
+    ChildrenNoType(ChunkDisk<R>),
 }
 
-impl ChunkDiskType {
-    pub fn from_chunk_disk(mut chunk: ChunkDisk) -> RiffResult<ChunkDiskType> {
+impl<R: Read + Seek> ChunkDiskType<R> {
+    pub fn from_chunk_disk(mut chunk: ChunkDisk<R>) -> RiffResult<ChunkDiskType<R>> {
         let chunk_id = chunk.id()?;
         let result = match chunk_id.as_bytes() {
             RIFF_ID | LIST_ID => ChunkDiskType::Children(chunk),
@@ -36,14 +45,27 @@ impl ChunkDiskType {
 /// `ChunkDisk` is an opaque type. The only way to access its content is by converting it into
 /// a `ChunkDiskContent`.
 
+/// An alias for [`ChunkDisk`] under the name used elsewhere for "a seekable, lazy reader over
+/// `R`": `ChunkDisk` already only parses the 8- (or 12-, for `RIFF`/`LIST`) byte header eagerly
+/// and seeks past each payload rather than reading it (see [`ChunkDiskIter::next`]), fetching
+/// payloads on demand via [`ChunkDisk::get_raw_child`]. This lets a caller enumerate and
+/// selectively extract chunks from a huge file, such as `Canimate.avi`, without resident-loading
+/// it, as [`crate::eager::riff::RiffRam`] does.
+pub type RiffReader<R> = ChunkDisk<R>;
+
 /// Represents a lazy reader of a chunk in a RIFF file.
+///
+/// `ChunkDisk` is generic over its backing store `R`, which only needs to implement
+/// `Read + Seek`. This allows it to sit on top of an on-disk `File` (see [`ChunkDisk::from_path`]),
+/// an in-memory `std::io::Cursor<Vec<u8>>`, or any other seekable source, while reusing the same
+/// seek-and-read access pattern.
 #[derive(Debug)]
-pub struct ChunkDisk {
+pub struct ChunkDisk<R> {
     pos: u32,
-    reader: RcReader,
+    reader: RcReader<R>,
 }
 
-impl ChunkDisk {
+impl<R: Read + Seek> ChunkDisk<R> {
     pub fn id(&mut self) -> RiffResult<FourCC> {
         let id = self.read_4_bytes_from_offset(0)?;
         let result = FourCC::new(&id);
@@ -62,26 +84,41 @@ impl ChunkDisk {
         Ok(result)
     }
 
-    fn from_reader(reader: &RcReader, offset: u32) -> ChunkDisk {
+    fn from_reader(reader: &RcReader<R>, offset: u32) -> ChunkDisk<R> {
         ChunkDisk {
             pos: offset,
             reader: reader.clone(),
         }
     }
 
-    pub fn from_path<P>(path: P) -> RiffResult<ChunkDisk>
-    where
-        P: AsRef<Path>,
-    {
-        let reader = Rc::new(RefCell::new(BufReader::new(File::open(&path)?)));
-        Ok(ChunkDisk { pos: 0, reader })
+    /// Creates a `ChunkDisk` directly from an owned `Read + Seek` source, such as a
+    /// `std::io::Cursor<Vec<u8>>` holding RIFF data that is already resident in memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use riffu::{error::RiffResult, lazy::riff::ChunkDisk};
+    /// # pub fn main() -> RiffResult<()> {
+    /// let data: Vec<u8> = vec![
+    ///     82, 73, 70, 70, 14, 0, 0, 0, 115, 109, 112, 108, 116, 101, 115, 116, 1, 0, 0, 0, 255, 0,
+    /// ];
+    /// let mut chunk_root = ChunkDisk::from_reader_owned(std::io::Cursor::new(data))?;
+    /// assert_eq!(chunk_root.id()?.as_bytes(), b"RIFF");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_reader_owned(reader: R) -> RiffResult<ChunkDisk<R>> {
+        Ok(ChunkDisk {
+            pos: 0,
+            reader: Rc::new(RefCell::new(reader)),
+        })
     }
 
     fn read_4_bytes_from_offset(&mut self, offset: u32) -> RiffResult<[u8; 4]> {
         let mut buffer = [0, 0, 0, 0];
         let pos = (self.pos + offset) as u64;
         let mut reader = self.reader.borrow_mut();
-        reader.seek(std::io::SeekFrom::Start(pos))?;
+        reader.seek(SeekFrom::Start(pos))?;
         reader.read_exact(&mut buffer)?;
         Ok(buffer)
     }
@@ -92,11 +129,19 @@ impl ChunkDisk {
         let offset = self.offset_into_data()? as u64;
         let mut result = vec![0; payload_len];
         let mut reader = self.reader.borrow_mut();
-        reader.seek(std::io::SeekFrom::Start(pos + offset))?;
+        reader.seek(SeekFrom::Start(pos + offset))?;
         reader.read_exact(&mut result)?;
         Ok(result)
     }
 
+    /// Like [`get_raw_child`](ChunkDisk::get_raw_child), but hands back an owned, cheaply-cloneable
+    /// `bytes::Bytes` instead of a `Vec<u8>`, so downstream code (e.g. a networking or media
+    /// pipeline) can share the payload across tasks without deep-copying it again.
+    #[cfg(feature = "bytes")]
+    pub fn payload_bytes(&mut self) -> RiffResult<bytes::Bytes> {
+        Ok(bytes::Bytes::from(self.get_raw_child()?))
+    }
+
     fn offset_into_data(&mut self) -> RiffResult<usize> {
         Ok(match self.id()?.as_bytes() {
             RIFF_ID | LIST_ID => 12,
@@ -104,7 +149,7 @@ impl ChunkDisk {
         })
     }
 
-    pub fn iter(&mut self) -> RiffResult<ChunkDiskIter> {
+    pub fn iter(&mut self) -> RiffResult<ChunkDiskIter<R>> {
         let result = match self.id()?.as_bytes() {
             LIST_ID | RIFF_ID => ChunkDiskIter {
                 cursor: self.pos + 12,
@@ -122,21 +167,35 @@ impl ChunkDisk {
         Ok(result)
     }
 
-    pub fn get_reader(&self) -> RcReader {
+    pub fn get_reader(&self) -> RcReader<R> {
         self.reader.clone()
     }
 }
 
+#[cfg(feature = "std")]
+impl ChunkDisk<BufReader<File>> {
+    /// Convenience constructor that opens `path` and wraps it in a `BufReader<File>`.
+    ///
+    /// For sources that are not on-disk files (in-memory buffers, mmap'd regions, ...), use
+    /// [`ChunkDisk::from_reader_owned`] instead.
+    pub fn from_path<P>(path: P) -> RiffResult<ChunkDisk<BufReader<File>>>
+    where
+        P: AsRef<Path>,
+    {
+        ChunkDisk::from_reader_owned(BufReader::new(File::open(&path)?))
+    }
+}
+
 #[derive(Debug)]
-pub struct ChunkDiskIter {
+pub struct ChunkDiskIter<R> {
     cursor: u32,
     cursor_end: u32,
-    reader: RcReader,
+    reader: RcReader<R>,
     error_occurred: bool,
 }
 
-impl Iterator for ChunkDiskIter {
-    type Item = RiffResult<ChunkDisk>;
+impl<R: Read + Seek> Iterator for ChunkDiskIter<R> {
+    type Item = RiffResult<ChunkDisk<R>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.error_occurred || self.cursor >= self.cursor_end {
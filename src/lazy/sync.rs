@@ -0,0 +1,164 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use crate::{
+    constants::{LIST_ID, RIFF_ID},
+    error::RiffResult,
+    FourCC,
+};
+
+/// A source that can be read from at an arbitrary offset without any `&mut self` cursor state,
+/// so handles derived from it can be shared across threads.
+///
+/// This is what lets [`ChunkDiskSync`] be `Send + Sync`: instead of serializing every read
+/// through one `RefCell`-guarded cursor (as [`crate::lazy::riff::ChunkDisk`] does), each read
+/// carries its own offset and borrows `&self` only.
+pub trait PositionedRead: Send + Sync {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> RiffResult<()>;
+}
+
+#[cfg(unix)]
+impl PositionedRead for File {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> RiffResult<()> {
+        use std::os::unix::fs::FileExt;
+        FileExt::read_exact_at(self, buf, offset)?;
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+impl PositionedRead for File {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> RiffResult<()> {
+        use std::os::windows::fs::FileExt;
+        let mut read = 0;
+        while read < buf.len() {
+            let n = FileExt::seek_read(self, &mut buf[read..], offset + read as u64)?;
+            if n == 0 {
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            }
+            read += n;
+        }
+        Ok(())
+    }
+}
+
+/// A `Send + Sync` lazy chunk handle, backed by an `Arc<P>` and positioned reads instead of the
+/// shared mutable cursor that [`crate::lazy::riff::ChunkDisk`] uses. This lets large RIFF/AVI
+/// files with hundreds of sibling chunks have their subtrees parsed concurrently; see
+/// [`ChunkDiskSync::par_iter`] (behind the `rayon` feature).
+#[derive(Debug, Clone)]
+pub struct ChunkDiskSync<P> {
+    pos: u32,
+    source: Arc<P>,
+}
+
+impl<P: PositionedRead> ChunkDiskSync<P> {
+    pub fn from_source(source: P) -> ChunkDiskSync<P> {
+        ChunkDiskSync {
+            pos: 0,
+            source: Arc::new(source),
+        }
+    }
+
+    fn from_arc(source: &Arc<P>, offset: u32) -> ChunkDiskSync<P> {
+        ChunkDiskSync {
+            pos: offset,
+            source: source.clone(),
+        }
+    }
+
+    pub fn id(&self) -> RiffResult<FourCC> {
+        let buf = self.read_4_bytes_from_offset(0)?;
+        Ok(FourCC::new(&buf))
+    }
+
+    pub fn payload_len(&self) -> RiffResult<u32> {
+        let buf = self.read_4_bytes_from_offset(4)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    pub fn chunk_type(&self) -> RiffResult<FourCC> {
+        let buf = self.read_4_bytes_from_offset(8)?;
+        Ok(FourCC::new(&buf))
+    }
+
+    fn read_4_bytes_from_offset(&self, offset: u32) -> RiffResult<[u8; 4]> {
+        let mut buf = [0u8; 4];
+        self.source.read_at(&mut buf, (self.pos + offset) as u64)?;
+        Ok(buf)
+    }
+
+    fn offset_into_data(&self) -> RiffResult<usize> {
+        Ok(match self.id()?.as_bytes() {
+            RIFF_ID | LIST_ID => 12,
+            _ => 8,
+        })
+    }
+
+    pub fn get_raw_child(&self) -> RiffResult<Vec<u8>> {
+        let offset = self.offset_into_data()? as u64;
+        let payload_len = self.payload_len()? as usize;
+        let mut result = vec![0; payload_len];
+        self.source
+            .read_at(&mut result, self.pos as u64 + offset)?;
+        Ok(result)
+    }
+
+    pub fn iter(&self) -> RiffResult<ChunkDiskSyncIter<P>> {
+        let result = match self.id()?.as_bytes() {
+            LIST_ID | RIFF_ID => ChunkDiskSyncIter {
+                cursor: self.pos + 12,
+                cursor_end: self.pos + 12 + self.payload_len()? - 4,
+                source: self.source.clone(),
+                error_occurred: false,
+            },
+            _ => ChunkDiskSyncIter {
+                cursor: self.pos + 8,
+                cursor_end: self.pos + 8 + self.payload_len()?,
+                source: self.source.clone(),
+                error_occurred: false,
+            },
+        };
+        Ok(result)
+    }
+
+    /// Bridges [`iter`](ChunkDiskSync::iter) onto the global `rayon` thread pool via
+    /// `ParallelBridge`, so callers can hand each sibling chunk's subtree to a worker, e.g.
+    /// `chunk.par_iter()?.for_each(|c| ...)`. Sound because `ChunkDiskSyncIter<P>` is `Send`
+    /// whenever `P: Send + Sync`.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter(&self) -> RiffResult<rayon::iter::IterBridge<ChunkDiskSyncIter<P>>> {
+        use rayon::iter::ParallelBridge;
+        Ok(self.iter()?.par_bridge())
+    }
+}
+
+#[derive(Debug)]
+pub struct ChunkDiskSyncIter<P> {
+    cursor: u32,
+    cursor_end: u32,
+    source: Arc<P>,
+    error_occurred: bool,
+}
+
+impl<P: PositionedRead> Iterator for ChunkDiskSyncIter<P> {
+    type Item = RiffResult<ChunkDiskSync<P>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error_occurred || self.cursor >= self.cursor_end {
+            return None;
+        }
+        let chunk = ChunkDiskSync::from_arc(&self.source, self.cursor);
+        match chunk.payload_len() {
+            Ok(len) => {
+                self.cursor += 8 + len + (len % 2);
+                Some(Ok(chunk))
+            }
+            Err(err) => {
+                self.error_occurred = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
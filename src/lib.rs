@@ -1,11 +1,34 @@
-use crate::error::RiffError;
+//! # Features
+//!
+//! - `std` (default): enables `File`-based conveniences such as `ChunkDisk::from_path` and
+//!   `RiffRam::from_file`.
+//! - `no_std`: builds against [`core_io`](https://docs.rs/core-io), the `#![no_std]` port of
+//!   `std::io`, instead of `std::io`, so the lazy reader and the builder can run on embedded
+//!   targets. An allocator (`alloc`) is still required for `Vec`/`Rc`. Disable the default
+//!   `std` feature to use this.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use crate::error::{RiffError, RiffErrorKind, RiffResult};
+#[cfg(feature = "std")]
 use std::convert::{TryFrom, TryInto};
 
+#[cfg(not(feature = "std"))]
+use core::convert::{TryFrom, TryInto};
+
 pub mod builder;
 pub mod constants;
 pub mod eager;
 pub mod error;
+#[cfg(feature = "std")]
+pub mod index;
+pub mod io;
 pub mod lazy;
+pub mod parser;
+#[cfg(feature = "bytes")]
+pub mod stream;
 
 #[derive(Debug, Clone)]
 pub struct FourCC {
@@ -35,6 +58,54 @@ impl FourCC {
         FourCC { data: *data }
     }
 
+    /// Builds a `FourCC`, rejecting any byte outside the printable-ASCII range the RIFF spec
+    /// allows for identifiers (`0x20`-`0x7E`); trailing spaces are permitted, since short IDs
+    /// like `"snd "` are padded with them.
+    ///
+    /// Prefer this over [`FourCC::new`] when the bytes come from an untrusted file, so malformed
+    /// tags surface as a [`RiffErrorKind::InvalidFourCC`] instead of silently round-tripping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use riffu::FourCC;
+    /// assert!(FourCC::new_checked(b"test").is_ok());
+    /// assert!(FourCC::new_checked(&[0x00, 0x01, 0x02, 0x03]).is_err());
+    /// ```
+    pub fn new_checked(data: &[u8; 4]) -> RiffResult<Self> {
+        FourCC::validate_bytes(data, 0)?;
+        Ok(FourCC { data: *data })
+    }
+
+    /// Checks that this already-built `FourCC` only contains printable ASCII bytes, so a caller
+    /// assembling a chunk id by hand (rather than through [`FourCC::new_checked`]) can catch a
+    /// bad identifier before writing it out, not while re-reading it later.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use riffu::FourCC;
+    /// assert!(FourCC::new(b"test").validate().is_ok());
+    /// assert!(FourCC::new(&[0x00, 0x01, 0x02, 0x03]).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> RiffResult<()> {
+        FourCC::validate_bytes(&self.data, 0)
+    }
+
+    /// Checks `data` for printable-ASCII bytes, tagging a failure with `position` (a byte offset
+    /// in whatever larger buffer `data` was read from, or `0` when there isn't one).
+    pub(crate) fn validate_bytes(data: &[u8; 4], position: u64) -> RiffResult<()> {
+        if data.iter().all(|b| (0x20..=0x7E).contains(b)) {
+            Ok(())
+        } else {
+            Err(RiffErrorKind::InvalidFourCC {
+                bytes: *data,
+                position,
+            }
+            .into())
+        }
+    }
+
     /// View `&self` struct as a `&[u8]`.
     pub fn as_bytes(&self) -> &[u8; 4] {
         &self.data
@@ -44,13 +115,20 @@ impl FourCC {
     pub fn into_bytes(self) -> [u8; 4] {
         self.data
     }
+
+    /// Views this `FourCC` as a `&str`, for display purposes. Returns `None` if the identifier
+    /// is not valid UTF-8 (which, per the RIFF spec, should only happen for malformed input).
+    pub fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(&self.data).ok()
+    }
 }
 
 /// A `&[u8]` can be converted to a `FourCC`.
 impl TryFrom<&[u8]> for FourCC {
     type Error = RiffError;
 
-    /// Performs the conversion.
+    /// Performs the conversion, rejecting non-printable-ASCII identifiers. See
+    /// [`FourCC::new_checked`].
     /// ```
     /// use riffu::FourCC;
     /// use std::convert::TryInto;
@@ -58,9 +136,7 @@ impl TryFrom<&[u8]> for FourCC {
     /// let test: FourCC = buffer.try_into().unwrap();
     /// ```
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(FourCC {
-            data: value.try_into()?,
-        })
+        FourCC::new_checked(&value.try_into()?)
     }
 }
 
@@ -68,13 +144,14 @@ impl TryFrom<&[u8]> for FourCC {
 impl TryFrom<&str> for FourCC {
     type Error = RiffError;
 
-    /// Performs the conversion.
+    /// Performs the conversion, rejecting non-printable-ASCII identifiers. See
+    /// [`FourCC::new_checked`].
     /// ```
     /// use riffu::FourCC;
     /// use std::convert::TryInto;
     /// let test : FourCC = "test".try_into().unwrap();
     /// ```
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Ok(value.as_bytes().try_into()?)
+        FourCC::new_checked(&value.as_bytes().try_into()?)
     }
 }
@@ -0,0 +1,205 @@
+//! An incremental, push-style parser for a single chunk header/payload/pad, for sources that
+//! cannot hand over a whole chunk in one contiguous buffer — a socket, a growing file, bytes
+//! arriving off an async stream. Modeled on hyper's `ChunkedState` machine: the caller owns the
+//! buffer and calls [`ChunkParser::advance`] with whatever bytes are currently available; the
+//! parser consumes as much as it can and reports back what it consumed plus, if a boundary was
+//! crossed, the [`ChunkEvent`] that crossing produced.
+//!
+//! Unlike [`crate::stream::ChunkStream`], which owns a `Read` and a `BytesMut` and walks a whole
+//! tree, a `ChunkParser` only frames one chunk and holds no I/O dependency at all — it works
+//! equally well under `no_std`, and a caller walking a tree stacks instances of it itself.
+
+use crate::{
+    error::{RiffErrorKind, RiffResult},
+    FourCC,
+};
+
+/// One boundary crossed while framing a chunk.
+#[derive(Debug, Clone)]
+pub enum ChunkEvent<'a> {
+    /// The 8-byte id/length header has been read in full.
+    Header { id: FourCC, len: u32 },
+    /// A slice of the chunk's payload, as much as was available in this call to `advance`.
+    Data(&'a [u8]),
+    /// The payload (and its pad byte, if `len` was odd) has been fully consumed.
+    End,
+}
+
+#[derive(Debug, Clone)]
+enum State {
+    /// Reading the 4-byte `FourCC`. `filled` is how many of `partial`'s bytes are valid.
+    Id { filled: u8 },
+    /// Id is known; reading the 4-byte little-endian length.
+    Len { id: FourCC, filled: u8 },
+    /// Header complete; `remaining` payload bytes (not counting the pad) are still owed.
+    Body { id: FourCC, remaining: u32, pad: bool },
+    /// The payload is done; one pad byte remains to be skipped if `len` was odd.
+    Pad { id: FourCC },
+    /// Framing is complete; `advance` is a no-op from here on.
+    Done,
+}
+
+/// A resumable state machine that frames exactly one chunk: its 8-byte header, its payload, and
+/// (for odd-length payloads) the RIFF word-alignment pad byte.
+///
+/// Create one per chunk. Feed it bytes via [`advance`](ChunkParser::advance) until it returns a
+/// [`ChunkEvent::End`]; a fresh `ChunkParser` is needed for the next sibling or child.
+#[derive(Debug, Clone)]
+pub struct ChunkParser {
+    state: State,
+    /// Accumulates a header field (`id` or `len`) that straddles two `advance` calls.
+    partial: [u8; 4],
+    /// The absolute offset this chunk's header starts at, used to tag a strict-mode
+    /// [`RiffErrorKind::InvalidFourCC`] with where the bad id was found.
+    offset: u64,
+    /// Whether to reject an id containing non-printable-ASCII bytes (see
+    /// [`with_strict_fourcc`](ChunkParser::with_strict_fourcc)) instead of passing it through.
+    strict_fourcc: bool,
+}
+
+impl Default for ChunkParser {
+    fn default() -> Self {
+        ChunkParser::new()
+    }
+}
+
+impl ChunkParser {
+    /// Creates a parser ready to read a new chunk's header.
+    pub fn new() -> Self {
+        ChunkParser::at(0)
+    }
+
+    /// Like [`new`](ChunkParser::new), but records `offset` as this chunk's absolute position in
+    /// the stream, so a strict-mode [`RiffErrorKind::InvalidFourCC`] reports something more
+    /// useful than `0`.
+    pub fn at(offset: u64) -> Self {
+        ChunkParser {
+            state: State::Id { filled: 0 },
+            partial: [0; 4],
+            offset,
+            strict_fourcc: false,
+        }
+    }
+
+    /// Opts into rejecting an id that isn't 4 printable-ASCII bytes, surfacing it as
+    /// [`RiffErrorKind::InvalidFourCC`] instead of letting it through (the default, since not
+    /// every caller wants to fail a whole parse over a cosmetic non-conformance in one id).
+    pub fn with_strict_fourcc(mut self) -> Self {
+        self.strict_fourcc = true;
+        self
+    }
+
+    /// Feeds `input` to the parser. Returns the number of bytes consumed from the front of
+    /// `input`, together with the event produced if a boundary was crossed. Bytes of a header
+    /// that is not yet complete are consumed and buffered, returning `(n, None)`. Returns
+    /// `(0, None)` when `input` is empty (or once the chunk is done) — the caller should supply
+    /// more bytes (e.g. after another socket read) and call again.
+    ///
+    /// A single call never crosses more than one boundary, so a caller driving this in a loop
+    /// should keep calling `advance` with the remainder of `input` (`&input[consumed..]`) until
+    /// it returns `(0, None)`.
+    pub fn advance<'a>(&mut self, input: &'a [u8]) -> RiffResult<(usize, Option<ChunkEvent<'a>>)> {
+        match &self.state {
+            State::Id { filled } => {
+                let filled = *filled;
+                let (consumed, filled) = self.fill_partial(input, filled as usize);
+                if filled < 4 {
+                    self.state = State::Id {
+                        filled: filled as u8,
+                    };
+                    return Ok((consumed, None));
+                }
+                if self.strict_fourcc {
+                    FourCC::validate_bytes(&self.partial, self.offset)?;
+                }
+                let id = FourCC::new(&self.partial);
+                self.state = State::Len { id, filled: 0 };
+                // The id alone is not a boundary: go straight on to the length with what is left.
+                let (more, event) = self.advance(&input[consumed..])?;
+                Ok((consumed + more, event))
+            }
+            State::Len { id, filled } => {
+                let id = id.clone();
+                let filled = *filled;
+                let (consumed, filled) = self.fill_partial(input, filled as usize);
+                if filled < 4 {
+                    self.state = State::Len {
+                        id,
+                        filled: filled as u8,
+                    };
+                    return Ok((consumed, None));
+                }
+                let len = u32::from_le_bytes(self.partial);
+                let pad = len % 2 == 1;
+                // `8 + len + pad` is the total framed size; only the arithmetic itself needs to
+                // stay in range, since `remaining`/the pad flag are tracked separately below.
+                8u32.checked_add(len)
+                    .and_then(|v| v.checked_add(pad as u32))
+                    .ok_or(RiffErrorKind::LengthOverflow { declared_len: len })?;
+                self.state = State::Body {
+                    id: id.clone(),
+                    remaining: len,
+                    pad,
+                };
+                Ok((consumed, Some(ChunkEvent::Header { id, len })))
+            }
+            State::Body {
+                id,
+                remaining,
+                pad,
+            } => {
+                let id = id.clone();
+                let remaining = *remaining;
+                let pad = *pad;
+                if remaining == 0 {
+                    if pad {
+                        // Only report `End` once the pad byte itself has been consumed: a caller
+                        // following this struct's "stop feeding bytes at `End`" contract would
+                        // otherwise leave it unconsumed for the next sibling to misread as the
+                        // first byte of its FourCC.
+                        if input.is_empty() {
+                            self.state = State::Pad { id };
+                            return Ok((0, None));
+                        }
+                        self.state = State::Done;
+                        return Ok((1, Some(ChunkEvent::End)));
+                    }
+                    self.state = State::Done;
+                    return Ok((0, Some(ChunkEvent::End)));
+                }
+                if input.is_empty() {
+                    return Ok((0, None));
+                }
+                let take = (remaining as usize).min(input.len());
+                self.state = State::Body {
+                    id,
+                    remaining: remaining - take as u32,
+                    pad,
+                };
+                Ok((take, Some(ChunkEvent::Data(&input[..take]))))
+            }
+            State::Pad { .. } => {
+                if input.is_empty() {
+                    return Ok((0, None));
+                }
+                self.state = State::Done;
+                Ok((1, Some(ChunkEvent::End)))
+            }
+            State::Done => Ok((0, None)),
+        }
+    }
+
+    /// Whether this parser has finished framing its chunk.
+    pub fn is_done(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+
+    /// Copies as many of `input`'s leading bytes as needed into `self.partial[already_filled..]`,
+    /// returning how many bytes were consumed and the new fill count.
+    fn fill_partial(&mut self, input: &[u8], already_filled: usize) -> (usize, usize) {
+        let want = 4 - already_filled;
+        let take = want.min(input.len());
+        self.partial[already_filled..already_filled + take].copy_from_slice(&input[..take]);
+        (take, already_filled + take)
+    }
+}
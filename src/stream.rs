@@ -0,0 +1,159 @@
+//! A pull-style, forward-only parser for RIFF data arriving over a plain `impl Read` (pipes,
+//! sockets, decompression streams) where seeking back to skip a chunk isn't possible.
+//!
+//! Unlike [`crate::lazy::riff::ChunkDisk`], which needs `Seek` to jump between siblings,
+//! [`ChunkStream`] only ever reads forward, buffering through a `bytes::BytesMut` so partial
+//! reads (a header or payload split across two `read` calls) are handled transparently.
+
+use bytes::{Buf, Bytes, BytesMut};
+use std::io::Read;
+
+use crate::{
+    constants::{LIST_ID, RIFF_ID, SEQT_ID},
+    error::RiffResult,
+    FourCC,
+};
+
+/// One step of the forward-only walk over a chunk tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChunkEvent {
+    /// A chunk header was read. `chunk_type` is `Some` for `RIFF`/`LIST` containers.
+    Enter {
+        id: FourCC,
+        chunk_type: Option<FourCC>,
+        len: u32,
+    },
+    /// Part (or all) of the current chunk's payload.
+    Payload(Bytes),
+    /// The current chunk's payload (and its pad byte, if any) has been fully consumed.
+    Leave,
+}
+
+/// Tracks how many bytes are still owed for one level of nesting, and whether a RIFF
+/// word-alignment pad byte needs to be skipped once `remaining` reaches zero.
+struct Frame {
+    remaining: u32,
+    pad: bool,
+    /// Whether `remaining` counts down bytes belonging to nested child headers (`RIFF`/`LIST`/
+    /// `seqt`) rather than one flat, opaque payload (a leaf chunk).
+    is_container: bool,
+}
+
+/// A pull-style event stream over `R`. Call [`next_event`](ChunkStream::next_event) in a loop
+/// until it returns `Ok(None)`.
+pub struct ChunkStream<R> {
+    reader: R,
+    buf: BytesMut,
+    stack: Vec<Frame>,
+}
+
+impl<R: Read> ChunkStream<R> {
+    pub fn new(reader: R) -> Self {
+        ChunkStream {
+            reader,
+            buf: BytesMut::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Ensures at least `n` bytes are buffered, reading more from `self.reader` as needed.
+    /// Returns the number of bytes actually available (less than `n` only at EOF).
+    fn fill(&mut self, n: usize) -> RiffResult<usize> {
+        while self.buf.len() < n {
+            let mut chunk = [0u8; 4096];
+            let read = self.reader.read(&mut chunk)?;
+            if read == 0 {
+                break;
+            }
+            self.buf.extend_from_slice(&chunk[..read]);
+        }
+        Ok(self.buf.len().min(n))
+    }
+
+    /// Produces the next event, or `Ok(None)` once the underlying reader is exhausted. On
+    /// well-formed input every open chunk has been closed by then; if the reader runs out in the
+    /// middle of a chunk, `Ok(None)` is returned with that chunk (and its parents) still open.
+    ///
+    /// Three things can happen, checked in order: the innermost open chunk is a leaf with
+    /// payload still owed, so the next slice of it is streamed; the innermost open chunk (leaf
+    /// or container) has nothing left owed, so it's closed out; or there's room for another
+    /// header — either the top-level chunk, or the next child of the innermost open container —
+    /// so one is parsed.
+    pub fn next_event(&mut self) -> RiffResult<Option<ChunkEvent>> {
+        if let Some(frame) = self.stack.last() {
+            if !frame.is_container && frame.remaining > 0 {
+                let want = (frame.remaining as usize).min(4096);
+                let available = self.fill(want)?;
+                if available == 0 {
+                    return Ok(None);
+                }
+                let data = self.buf.split_to(available).freeze();
+                self.consume(available);
+                return Ok(Some(ChunkEvent::Payload(data)));
+            }
+            if frame.remaining == 0 {
+                let pad = frame.pad;
+                if pad && self.fill(1)? > 0 {
+                    self.buf.advance(1);
+                    self.consume(1);
+                }
+                self.stack.pop();
+                return Ok(Some(ChunkEvent::Leave));
+            }
+        }
+
+        let header_len = 8;
+        if self.fill(header_len)? < header_len {
+            return Ok(None);
+        }
+        let mut id_buf = [0u8; 4];
+        id_buf.copy_from_slice(&self.buf[0..4]);
+        let id = FourCC::new(&id_buf);
+        let mut len_buf = [0u8; 4];
+        len_buf.copy_from_slice(&self.buf[4..8]);
+        let len = u32::from_le_bytes(len_buf);
+        let pad = (len % 2) == 1;
+
+        let has_type = id.as_bytes() == &RIFF_ID || id.as_bytes() == &LIST_ID;
+        let is_container = has_type || id.as_bytes() == &SEQT_ID;
+        if has_type {
+            self.fill(12)?;
+            let mut type_buf = [0u8; 4];
+            type_buf.copy_from_slice(&self.buf[8..12]);
+            let chunk_type = FourCC::new(&type_buf);
+            self.buf.advance(12);
+            self.consume(12);
+            self.stack.push(Frame {
+                remaining: len - 4,
+                pad,
+                is_container: true,
+            });
+            Ok(Some(ChunkEvent::Enter {
+                id,
+                chunk_type: Some(chunk_type),
+                len,
+            }))
+        } else {
+            self.buf.advance(8);
+            self.consume(8);
+            self.stack.push(Frame {
+                remaining: len,
+                pad,
+                is_container,
+            });
+            Ok(Some(ChunkEvent::Enter {
+                id,
+                chunk_type: None,
+                len,
+            }))
+        }
+    }
+
+    /// Charges `n` consumed bytes against every currently open frame, since bytes belonging to a
+    /// nested chunk also count against how much its enclosing containers still owe.
+    fn consume(&mut self, n: usize) {
+        for frame in &mut self.stack {
+            frame.remaining = frame.remaining.saturating_sub(n as u32);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+extern crate riffu;
+
+use core::mem::size_of;
+use riffu::lazy::buffered::{map_struct, BufferedReader, ChunkHeader, SliceBuffer};
+use std::io::Cursor;
+
+/// A chunk header that straddles the end of `SliceBuffer`'s 64KB window must still be read in
+/// full rather than truncated to whatever's left in the already-buffered window.
+#[test]
+fn test_buffered_read_across_window_boundary() {
+    const WINDOW: usize = 64 * 1024;
+    let straddle_offset = WINDOW - 6;
+
+    let mut data = vec![0u8; straddle_offset];
+    data.extend_from_slice(b"test"); // id
+    data.extend_from_slice(&100u32.to_le_bytes()); // len
+    data.extend_from_slice(&[0u8; 64]); // trailing bytes past the header
+
+    let mut buf = SliceBuffer::new(Cursor::new(data));
+
+    // Prime the window so it covers [0, WINDOW), putting `straddle_offset` 6 bytes from the end.
+    buf.buffered_read(0, 8).unwrap();
+
+    let header = map_struct(
+        buf.buffered_read(straddle_offset as u64, size_of::<ChunkHeader>())
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(header.id().as_bytes(), b"test");
+    assert_eq!(header.payload_len(), 100);
+}
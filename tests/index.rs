@@ -0,0 +1,22 @@
+extern crate riffu;
+
+use riffu::{index::FourCCIndex, lazy::riff::ChunkDisk};
+
+#[test]
+fn test_build_and_find_all_set_3() {
+    // `set_3.riff` contains a `LIST`/`tst1` with two `test` leaf chunks holding raw string
+    // payloads, and a `seqt` with one `test` leaf. Neither leaf's payload is itself a sequence
+    // of valid chunk headers, so building an index over this tree would previously fail with an
+    // I/O/EOF error the moment `scan` recursed into a leaf's payload via `chunk.iter()`.
+    let mut chunk_root = ChunkDisk::from_path("test_assets/set_3.riff").unwrap();
+    let index = FourCCIndex::build(&mut chunk_root).unwrap();
+
+    assert!(index.may_contain(b"test"));
+    assert!(index.may_contain(b"RIFF"));
+    assert!(!index.may_contain(b"nope"));
+
+    let mut chunk_root = ChunkDisk::from_path("test_assets/set_3.riff").unwrap();
+    let found = index.find_all(&mut chunk_root, b"test").unwrap();
+    assert_eq!(found.len(), 3);
+    assert!(found.iter().all(|id| id.as_bytes() == b"test"));
+}
@@ -0,0 +1,103 @@
+extern crate riffu;
+
+use riffu::parser::{ChunkEvent, ChunkParser};
+
+/// An even-length payload needs no pad byte, so `End` should fire exactly once, right after the
+/// payload's last byte is consumed.
+#[test]
+fn test_even_length_payload_emits_end_once() {
+    let mut data = b"test".to_vec();
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"abcd");
+
+    let mut parser = ChunkParser::new();
+    let mut offset = 0;
+    let mut end_count = 0;
+    while !parser.is_done() {
+        let (consumed, event) = parser.advance(&data[offset..]).unwrap();
+        offset += consumed;
+        if matches!(event, Some(ChunkEvent::End)) {
+            end_count += 1;
+        }
+    }
+
+    assert_eq!(end_count, 1);
+    assert_eq!(offset, data.len());
+}
+
+/// An odd-length payload has a trailing pad byte. Per this struct's contract ("feed bytes until
+/// it returns `End`; a fresh parser is needed for the next sibling"), `End` must fire exactly
+/// once, and only after that pad byte has actually been consumed — otherwise a caller stops
+/// feeding bytes one byte too early and the next sibling's header gets misaligned.
+#[test]
+fn test_odd_length_payload_consumes_pad_before_end() {
+    let mut data = b"test".to_vec();
+    data.extend_from_slice(&3u32.to_le_bytes());
+    data.extend_from_slice(b"abc");
+    data.push(0); // pad byte
+    data.extend_from_slice(b"next"); // a sibling header's id, must remain untouched
+
+    let mut parser = ChunkParser::new();
+    let mut offset = 0;
+    let mut end_count = 0;
+    loop {
+        let (consumed, event) = parser.advance(&data[offset..]).unwrap();
+        offset += consumed;
+        if matches!(event, Some(ChunkEvent::End)) {
+            end_count += 1;
+        }
+        if parser.is_done() {
+            break;
+        }
+        if consumed == 0 && event.is_none() {
+            panic!("parser stalled before consuming the pad byte");
+        }
+    }
+
+    assert_eq!(end_count, 1);
+    // Exactly the header + payload + pad (8 + 3 + 1 = 12 bytes) should have been consumed,
+    // leaving the next sibling's bytes untouched.
+    assert_eq!(offset, 12);
+    assert_eq!(&data[offset..offset + 4], b"next");
+}
+
+#[test]
+fn test_strict_fourcc_accepts_printable_ascii_id() {
+    let mut data = b"fmt ".to_vec();
+    data.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut parser = ChunkParser::new().with_strict_fourcc();
+    let (consumed, event) = parser.advance(&data).unwrap();
+    assert_eq!(consumed, data.len());
+    match event {
+        Some(ChunkEvent::Header { id, len }) => {
+            assert_eq!(id.as_bytes(), b"fmt ");
+            assert_eq!(len, 0);
+        }
+        other => panic!("expected Header event, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_strict_fourcc_rejects_non_printable_id() {
+    let mut data = vec![0x00, 0x01, 0x02, 0x03];
+    data.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut parser = ChunkParser::new().with_strict_fourcc();
+    assert!(parser.advance(&data).is_err());
+}
+
+#[test]
+fn test_non_strict_fourcc_passes_through_non_printable_id() {
+    let mut data = vec![0x00, 0x01, 0x02, 0x03];
+    data.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut parser = ChunkParser::new();
+    let (_, event) = parser.advance(&data).unwrap();
+    match event {
+        Some(ChunkEvent::Header { id, .. }) => {
+            assert_eq!(id.as_bytes(), &[0x00, 0x01, 0x02, 0x03]);
+        }
+        other => panic!("expected Header event, got {:?}", other),
+    }
+}
@@ -0,0 +1,81 @@
+extern crate riffu;
+
+use riffu::{
+    builder::{
+        riff::{ChunkBuilder, ChunkData, RiffBuilder},
+        rf64::{write_rf64, Ds64Builder},
+    },
+    eager::{riff::RiffRam, rf64::Ds64Info},
+    FourCC,
+};
+
+/// Builds and writes a small (well under 4 GiB) `RF64` file through the builder, then reads it
+/// back through `RiffRam`/`ChunkRam`, exercising the same code paths a genuinely oversized file
+/// would use — `RiffRam::from_file` accepting the `RF64` header, and `ChunkRam::iter_rf64`
+/// walking its children — without actually allocating gigabytes of payload in a test.
+#[test]
+fn test_rf64_round_trip() {
+    let riff = RiffBuilder::new(FourCC::new(b"WAVE")).add_chunk(ChunkBuilder::new_notype(
+        FourCC::new(b"data"),
+        ChunkData::RawData(vec![1, 2, 3, 4]),
+    ));
+    let ds64 = Ds64Builder::new(riff.payload_len as u64, 4);
+
+    let mut bytes = Vec::new();
+    write_rf64(&riff, &ds64, &mut bytes).unwrap();
+
+    let path = std::env::temp_dir().join("riffu_test_rf64_round_trip.rf64");
+    std::fs::write(&path, &bytes).unwrap();
+    let riff_ram = RiffRam::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(riff_ram.is_rf64());
+    let ds64_info: Ds64Info = riff_ram.ds64().unwrap().unwrap();
+    assert_eq!(ds64_info.riff_size, riff.payload_len as u64);
+    assert_eq!(ds64_info.data_size, 4);
+
+    let root = riff_ram.root_chunk_rf64();
+    let children: Vec<_> = root.iter_rf64(&ds64_info).collect::<Result<_, _>>().unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].id().as_bytes(), b"data");
+    assert_eq!(children[0].payload_len_64(&ds64_info), 4);
+}
+
+/// The same shape as [`test_rf64_round_trip`], but with the `data` chunk's own 32-bit length
+/// field carrying the RF64 `OVERSIZED` sentinel (as a real >4 GiB file would), so `iter_rf64`
+/// has to recover its true size from `ds64` rather than from the header it's iterating over.
+#[test]
+fn test_rf64_iter_recovers_oversized_child_from_ds64() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"RF64");
+    bytes.extend_from_slice(&0xFFFF_FFFFu32.to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+
+    // `ds64`: riff_size, data_size, sample_count, and an empty override table.
+    let riff_size: u64 = 4 /* WAVE */ + (8 + 28) /* ds64 */ + (8 + 4) /* data */;
+    bytes.extend_from_slice(b"ds64");
+    bytes.extend_from_slice(&28u32.to_le_bytes());
+    bytes.extend_from_slice(&riff_size.to_le_bytes());
+    bytes.extend_from_slice(&4u64.to_le_bytes()); // data_size
+    bytes.extend_from_slice(&0u64.to_le_bytes()); // sample_count
+    bytes.extend_from_slice(&0u32.to_le_bytes()); // table length
+
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&0xFFFF_FFFFu32.to_le_bytes()); // OVERSIZED sentinel
+    bytes.extend_from_slice(&[1, 2, 3, 4]);
+
+    let path = std::env::temp_dir().join("riffu_test_rf64_oversized_child.rf64");
+    std::fs::write(&path, &bytes).unwrap();
+    let riff_ram = RiffRam::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let ds64_info = riff_ram.ds64().unwrap().unwrap();
+    assert_eq!(ds64_info.data_size, 4);
+
+    let root = riff_ram.root_chunk_rf64();
+    let children: Vec<_> = root.iter_rf64(&ds64_info).collect::<Result<_, _>>().unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].id().as_bytes(), b"data");
+    assert_eq!(children[0].payload_len(), 0xFFFF_FFFF);
+    assert_eq!(children[0].payload_len_64(&ds64_info), 4);
+}
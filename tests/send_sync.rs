@@ -0,0 +1,13 @@
+extern crate riffu;
+
+use riffu::lazy::sync::ChunkDiskSync;
+use std::fs::File;
+
+fn is_send<T: Send>() {}
+fn is_sync<T: Sync>() {}
+
+#[test]
+fn chunk_disk_sync_is_send_and_sync() {
+    is_send::<ChunkDiskSync<File>>();
+    is_sync::<ChunkDiskSync<File>>();
+}
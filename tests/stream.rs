@@ -0,0 +1,45 @@
+extern crate riffu;
+
+use riffu::stream::{ChunkEvent, ChunkStream};
+use std::fs::File;
+
+#[test]
+fn test_set_3_walks_nested_containers_and_seqt() {
+    let file = File::open("test_assets/set_3.riff").unwrap();
+    let mut stream = ChunkStream::new(file);
+
+    let mut events = Vec::new();
+    while let Some(event) = stream.next_event().unwrap() {
+        events.push(event);
+    }
+
+    let ids: Vec<(&[u8], bool)> = events
+        .iter()
+        .filter_map(|event| match event {
+            ChunkEvent::Enter { id, chunk_type, .. } => {
+                Some((id.as_bytes().as_slice(), chunk_type.is_some()))
+            }
+            _ => None,
+        })
+        .collect();
+
+    // RIFF > (LIST > test, test) and RIFF > (seqt > test) must all be entered individually,
+    // not swallowed as one opaque payload blob of the RIFF/LIST/seqt container.
+    assert_eq!(
+        ids,
+        vec![
+            (b"RIFF".as_slice(), true),
+            (b"LIST".as_slice(), true),
+            (b"test".as_slice(), false),
+            (b"test".as_slice(), false),
+            (b"seqt".as_slice(), false),
+            (b"test".as_slice(), false),
+        ]
+    );
+
+    let leave_count = events
+        .iter()
+        .filter(|event| matches!(event, ChunkEvent::Leave))
+        .count();
+    assert_eq!(leave_count, ids.len());
+}